@@ -1,8 +1,13 @@
 // pattern: Functional Core
 
+use crate::dlc::Dlc;
+use crate::economy::Economy;
+use crate::names::Names;
 use crate::rules::Constraint;
 use crate::tank::{Interior, Salinity, Temperature};
 use crate::util::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Growth {
@@ -20,6 +25,40 @@ pub struct Animal {
     pub growth: Growth,
 }
 
+/// How an animal is doing according to the game, as recorded in a save.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AnimalState {
+    /// percentages, None if the save doesn't record them
+    pub health: Option<u8>,
+    pub happiness: Option<u8>,
+    /// the game's own names for the needs it says aren't met, e.g. `needsLight`
+    pub unmet_needs: Vec<String>,
+}
+
+/// Where a save puts an animal, from the object its `hosting` points to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Hosting {
+    /// in the tank of an exhibit, by tank uid
+    Exhibit(u64),
+    /// in something that isn't an exhibit, e.g. a holding or quarantine tank
+    Holding(u64),
+    /// being carried by a member of staff, by their uid
+    InTransit(u64),
+    /// not hosted, or hosted by something that isn't in the game world
+    Unknown,
+}
+
+impl fmt::Display for Hosting {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Hosting::Exhibit(tank) => write!(f, "in the exhibit with tank {}", tank),
+            Hosting::Holding(host) => write!(f, "held outside any exhibit by {}", host),
+            Hosting::InTransit(staff) => write!(f, "being carried by staff {}", staff),
+            Hosting::Unknown => write!(f, "somewhere unknown"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct AnimalRef<'a> {
     pub id: AnimalId,
@@ -27,7 +66,7 @@ pub struct AnimalRef<'a> {
     pub growth: Growth,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Species {
     pub id: String,
     pub genus: String,
@@ -44,18 +83,56 @@ pub struct Species {
     pub predation: Vec<PreyType>,
     pub communal: Option<u8>,
     pub breeding: Breeding,
+    pub names: Names,
+    pub economy: Economy,
+    pub unlock_level: Option<u8>,
+    pub dlc: Option<Dlc>,
+    pub mod_name: Option<String>,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Breedable {
     pub baby: String,
+    /// days between births
+    pub interval: Option<u16>,
+    pub conditions: Vec<BreedingCondition>,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BreedingCondition {
+    /// needs another adult of its species in the tank
+    Partner,
+    /// needs at least this many of its species in the tank
+    Group(u8),
+    /// needs to be at least this happy
+    Happiness(u8),
+}
+
+impl fmt::Display for BreedingCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BreedingCondition::Partner => write!(f, "needs a partner"),
+            BreedingCondition::Group(n) => write!(f, "needs a group of {}", n),
+            BreedingCondition::Happiness(h) => write!(f, "needs happiness {}", h),
+        }
+    }
+}
+
+/// An egg, fry or other young form of a species.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Young {
+    /// the next form in the life cycle, either given by the game data or linked by id once all
+    /// species are loaded
+    pub grows_into: Option<String>,
+    /// days spent in the final stage before growing into the next form
+    pub duration: Option<u16>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum Breeding {
     Breedable(Breedable),
     CannotBread,
-    NotFullyGrown,
+    NotFullyGrown(Young),
 }
 
 impl AnimalRef<'_> {
@@ -208,7 +285,7 @@ impl Species {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum PreyType {
     Baby,
     Fish,
@@ -262,14 +339,14 @@ impl PreyType {
 
 as_str_display!(PreyType);
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Diet {
     Food { food: String, period: u16, skill: u8 },
     Scavenger,
     DoesNotEat,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Size {
     pub stages: Vec<Stage>,
     pub final_size: u16,
@@ -277,13 +354,13 @@ pub struct Size {
     pub immobile: bool,
 }
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
 pub struct Stage {
     pub size: u16,
     pub duration: u16,
 }
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
 pub struct Habitat {
     pub minimum_quality: u8,
     pub temperature: Temperature,
@@ -294,7 +371,7 @@ pub struct Habitat {
     pub territorial: bool,
 }
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
 pub struct Needs {
     pub light: Option<Need>,
     pub plants: Option<Need>,
@@ -308,20 +385,20 @@ pub struct Needs {
     pub explorer: Option<u8>,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Need {
     Loves(u8),
     Dislikes,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Shoaling {
     pub count: u8,
     pub one_ok: bool,
     pub two_ok: bool,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Fighting {
     Wimp,
     Bully,
@@ -338,7 +415,7 @@ impl Fighting {
 
 as_str_display!(Fighting);
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Nibbling {
     Nibbleable,
     Nibbler,
@@ -355,7 +432,7 @@ impl Nibbling {
 
 as_str_display!(Nibbling);
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Cohabitation {
     OnlyCongeners,
     NoConspecifics,
@@ -423,6 +500,11 @@ pub mod test {
             predation: Vec::new(),
             communal: None,
             breeding: Breeding::CannotBread,
+            economy: Economy::default(),
+            names: Names::default(),
+            unlock_level: None,
+            dlc: None,
+            mod_name: None,
         }
     }
 
@@ -2,14 +2,18 @@
 
 use crate::animal::*;
 use crate::data::GameData;
+use crate::equipment::{Equipment, EquipmentRef, LifeSupport};
 use crate::fixture::{Fixture, FixtureRef};
 use crate::rules::RuleOptions;
 use crate::tank::*;
 use crate::util::Result;
+use std::collections::{BTreeMap, BTreeSet};
 
 #[derive(Debug)]
 pub struct AquariumRef<'a> {
     pub exhibits: Vec<ExhibitRef<'a>>,
+    /// ids made up for summarized animals, which nobody can find in game
+    pub numbered_animals: BTreeSet<AnimalId>,
 }
 
 #[derive(Debug)]
@@ -18,22 +22,66 @@ pub struct ExhibitRef<'a> {
     pub tank: TankRef<'a>,
     pub animals: Vec<AnimalRef<'a>>,
     pub fixtures: Vec<FixtureRef<'a>>,
+    pub equipment: Vec<EquipmentRef<'a>>,
+    pub location: Location,
 }
 
-#[derive(Debug)]
+/// Where an exhibit's tank is in the park, as far as the save says.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Location {
+    /// as the game numbers them
+    pub floor: Option<u8>,
+    /// the name of the zone the player put the tank in
+    pub zone: Option<String>,
+    /// tile coordinates of the tank on its floor
+    pub position: Option<(i32, i32)>,
+}
+
+impl Location {
+    /// The floor and zone, e.g. "floor 2, zone Tropical", None if the save has neither.
+    pub fn label(&self) -> Option<String> {
+        let floor = self.floor.map(|f| format!("floor {}", f));
+        let zone = self.zone.as_ref().map(|z| format!("zone {}", z));
+        let parts: Vec<String> = floor.into_iter().chain(zone).collect();
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(", "))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct AquariumDesc {
     pub exhibits: Vec<ExhibitDesc>,
+    /// animals the save has outside every exhibit, only listed so they aren't forgotten; they
+    /// aren't read back
+    pub elsewhere: Vec<ElsewhereDesc>,
 }
 
-#[derive(Debug)]
+/// An animal that isn't in any exhibit, and where it is instead.
+#[derive(Debug, Clone)]
+pub struct ElsewhereDesc {
+    pub animal: Animal,
+    pub hosting: Hosting,
+    /// display name of its species, if it has one in the chosen language
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone)]
 pub struct ExhibitDesc {
     pub name: String,
     pub tank: Tank,
     pub animals: Vec<AnimalDesc>,
     pub fixtures: Vec<FixtureDesc>,
+    pub equipment: Vec<Equipment>,
+    pub location: Location,
+    /// display names of the species and fixtures in the exhibit by id, for whoever reads the
+    /// description; only the ids are used to find them again
+    pub names: BTreeMap<String, String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum AnimalDesc {
     Individual(Animal),
     Summary(SpeciesCount),
@@ -45,7 +93,7 @@ pub struct SpeciesCount {
     pub count: u16,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum FixtureDesc {
     Individual(Fixture),
     Summary(FixtureCount),
@@ -87,6 +135,10 @@ impl ExhibitRef<'_> {
             different_decorations: distinct_models.len() as u8,
         }
     }
+
+    pub fn life_support(&self) -> LifeSupport {
+        LifeSupport::of(&self.equipment)
+    }
 }
 
 fn animals_to_counts(animals: &[AnimalRef]) -> Vec<SpeciesCount> {
@@ -154,7 +206,17 @@ fn fixtures_to_counts(fixtures: &[FixtureRef]) -> Vec<FixtureCount> {
 }
 
 impl AquariumRef<'_> {
-    pub fn description(&self, summarize: bool) -> AquariumDesc {
+    /// Treats every animal as fully grown, as `RuleOptions::assume_all_fish_fully_grown` asks.
+    pub fn assume_fully_grown(&mut self) {
+        for exhibit in &mut self.exhibits {
+            for animal in &mut exhibit.animals {
+                animal.growth = Growth::Final;
+            }
+        }
+    }
+
+    /// `lang` is the language of the display names that go along with the ids.
+    pub fn description(&self, summarize: bool, lang: &str) -> AquariumDesc {
         let exhibits = self
             .exhibits
             .iter()
@@ -186,18 +248,58 @@ impl AquariumRef<'_> {
                             })
                             .collect()
                     },
+                    equipment: e
+                        .equipment
+                        .iter()
+                        .map(|x| Equipment {
+                            id: x.id,
+                            model: x.model.id.clone(),
+                        })
+                        .collect(),
+                    location: e.location.clone(),
+                    names: e
+                        .animals
+                        .iter()
+                        .map(|a| (&a.species.id, &a.species.names))
+                        .chain(e.fixtures.iter().map(|f| (&f.model.id, &f.model.names)))
+                        .filter_map(|(id, names)| Some((id.clone(), names.get(lang)?.to_string())))
+                        .collect(),
                 }
             })
             .collect();
 
-        AquariumDesc { exhibits }
+        AquariumDesc {
+            exhibits,
+            elsewhere: vec![],
+        }
     }
 }
 
 impl AquariumDesc {
+    /// Individual animals and fixtures keep the uids they have in the save, summarized ones are
+    /// numbered after the largest of those so the two never collide.
     pub fn to_ref<'a>(&self, data: &'a GameData, options: &RuleOptions) -> Result<AquariumRef<'a>> {
-        let mut counter = 0;
-        let mut fixture_counter: u64 = 0;
+        let mut counter = self
+            .exhibits
+            .iter()
+            .flat_map(|e| &e.animals)
+            .filter_map(|a| match a {
+                AnimalDesc::Individual(a) => Some(a.id),
+                AnimalDesc::Summary(_) => None,
+            })
+            .max()
+            .unwrap_or(0);
+        let mut numbered_animals = BTreeSet::new();
+        let mut fixture_counter = self
+            .exhibits
+            .iter()
+            .flat_map(|e| &e.fixtures)
+            .filter_map(|f| match f {
+                FixtureDesc::Individual(f) => Some(f.id),
+                FixtureDesc::Summary(_) => None,
+            })
+            .max()
+            .unwrap_or(0);
 
         let exhibits: Result<Vec<_>> = self
             .exhibits
@@ -213,6 +315,7 @@ impl AquariumDesc {
                             let species = data.species_ref(species)?;
                             for _ in 0..*count {
                                 counter += 1;
+                                numbered_animals.insert(counter);
                                 let growth = if options.assume_all_fish_fully_grown {
                                     Growth::Final
                                 } else {
@@ -225,16 +328,15 @@ impl AquariumDesc {
                                 })
                             }
                         }
-                        AnimalDesc::Individual(Animal { species, growth, .. }) => {
+                        AnimalDesc::Individual(Animal { id, species, growth }) => {
                             let species = data.species_ref(species)?;
-                            counter += 1;
                             let effective_growth = if options.assume_all_fish_fully_grown {
                                 Growth::Final
                             } else {
                                 *growth
                             };
                             animals.push(AnimalRef {
-                                id: counter,
+                                id: *id,
                                 species,
                                 growth: effective_growth,
                             })
@@ -256,27 +358,39 @@ impl AquariumDesc {
                                 });
                             }
                         }
-                        FixtureDesc::Individual(Fixture { model, .. }) => {
+                        FixtureDesc::Individual(Fixture { id, model }) => {
                             let model = data.fixture_ref(model)?;
-                            fixture_counter += 1;
-                            fixtures.push(FixtureRef {
-                                id: fixture_counter,
-                                model,
-                            });
+                            fixtures.push(FixtureRef { id: *id, model });
                         }
                     }
                 }
 
+                let equipment = exhibit
+                    .equipment
+                    .iter()
+                    .map(|e| {
+                        Ok(EquipmentRef {
+                            id: e.id,
+                            model: data.equipment_ref(&e.model)?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
                 Ok(ExhibitRef {
                     name: exhibit.name.clone(),
                     animals,
                     tank,
                     fixtures,
+                    equipment,
+                    location: exhibit.location.clone(),
                 })
             })
             .collect();
 
-        Ok(AquariumRef { exhibits: exhibits? })
+        Ok(AquariumRef {
+            exhibits: exhibits?,
+            numbered_animals,
+        })
     }
 }
 
@@ -285,7 +399,10 @@ mod test {
     use super::*;
     use crate::animal::test::*;
     use crate::data::GameData;
+    use crate::economy::Economy;
+    use crate::fixture::test::test_fixture_model;
     use crate::fixture::FixtureModel;
+    use crate::names::Names;
     use crate::rules::RuleOptions;
     use crate::tank::test::test_tank_model;
     use crate::tank::{Interior, LoadedEnvironment, TankModel, TankRef};
@@ -371,8 +488,7 @@ mod test {
         let data = GameData {
             species: vec![species],
             tanks: vec![tank_model],
-            fixtures: vec![],
-            food: vec![],
+            ..GameData::default()
         };
 
         let aquarium_desc = AquariumDesc {
@@ -389,7 +505,11 @@ mod test {
                     growth: Growth::Growing { stage: 0, growth: 5 },
                 })],
                 fixtures: vec![],
+                equipment: vec![],
+                location: Default::default(),
+                names: Default::default(),
             }],
+            elsewhere: vec![],
         };
 
         let options = RuleOptions {
@@ -407,8 +527,7 @@ mod test {
         let data = GameData {
             species: vec![species],
             tanks: vec![tank_model],
-            fixtures: vec![],
-            food: vec![],
+            ..GameData::default()
         };
 
         let aquarium_desc = AquariumDesc {
@@ -425,7 +544,11 @@ mod test {
                     growth: Growth::Growing { stage: 0, growth: 5 },
                 })],
                 fixtures: vec![],
+                equipment: vec![],
+                location: Default::default(),
+                names: Default::default(),
             }],
+            elsewhere: vec![],
         };
 
         let options = RuleOptions {
@@ -436,6 +559,65 @@ mod test {
         assert_eq!(result.exhibits[0].animals[0].growth, Growth::Final);
     }
 
+    #[test]
+    fn test_to_ref_keeps_save_uids() {
+        let data = GameData {
+            species: vec![test_species("goldfish")],
+            tanks: vec![test_tank_model("basic_tank")],
+            fixtures: vec![test_fixture_model("rock")],
+            ..GameData::default()
+        };
+
+        let aquarium_desc = AquariumDesc {
+            exhibits: vec![ExhibitDesc {
+                name: "Tank1".to_string(),
+                tank: Tank {
+                    id: 100,
+                    model: "basic_tank".to_string(),
+                    size: (5, 5),
+                },
+                animals: vec![
+                    AnimalDesc::Individual(Animal {
+                        id: 107,
+                        species: "goldfish".to_string(),
+                        growth: Growth::Final,
+                    }),
+                    AnimalDesc::Summary(SpeciesCount {
+                        species: "goldfish".to_string(),
+                        count: 1,
+                    }),
+                ],
+                fixtures: vec![
+                    FixtureDesc::Summary(FixtureCount {
+                        model: "rock".to_string(),
+                        count: 2,
+                    }),
+                    FixtureDesc::Individual(Fixture {
+                        id: 2,
+                        model: "rock".to_string(),
+                    }),
+                ],
+                equipment: vec![],
+                location: Default::default(),
+                names: Default::default(),
+            }],
+            elsewhere: vec![],
+        };
+
+        let options = RuleOptions {
+            assume_all_fish_fully_grown: false,
+        };
+        let mut result = aquarium_desc.to_ref(&data, &options).unwrap();
+        let ids: Vec<_> = result.exhibits[0].animals.iter().map(|a| a.id).collect();
+        assert_eq!(ids, vec![107, 108]);
+        assert_eq!(result.numbered_animals, BTreeSet::from([108]));
+        let fixture_ids: Vec<_> = result.exhibits[0].fixtures.iter().map(|f| f.id).collect();
+        assert_eq!(fixture_ids, vec![3, 4, 2]);
+
+        result.assume_fully_grown();
+        assert_eq!(result.exhibits[0].animals[1].growth, Growth::Final);
+    }
+
     #[test]
     fn test_loaded_environment() {
         let tank_model = TankModel {
@@ -444,6 +626,11 @@ mod test {
             max_size: (10, 10),
             double_density: 4,
             interior: Some(Interior::Rounded),
+            economy: Economy::default(),
+            names: Names::default(),
+            unlock_level: None,
+            dlc: None,
+            mod_name: None,
         };
 
         let fixture_model_a = FixtureModel {
@@ -456,6 +643,11 @@ mod test {
             flat_surfaces: Some(3),
             vertical_surfaces: Some(1),
             fluffy_foliage: Some(2),
+            economy: Economy::default(),
+            names: Names::default(),
+            unlock_level: None,
+            dlc: None,
+            mod_name: None,
         };
 
         let fixture_model_b = FixtureModel {
@@ -468,6 +660,11 @@ mod test {
             flat_surfaces: Some(1),
             vertical_surfaces: Some(3),
             fluffy_foliage: Some(4),
+            economy: Economy::default(),
+            names: Names::default(),
+            unlock_level: None,
+            dlc: None,
+            mod_name: None,
         };
 
         let exhibit = ExhibitRef {
@@ -488,6 +685,8 @@ mod test {
                     model: &fixture_model_b,
                 },
             ],
+            equipment: vec![],
+            location: Default::default(),
         };
 
         let result = exhibit.loaded_environment();
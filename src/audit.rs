@@ -0,0 +1,82 @@
+// pattern: Functional Core
+
+// When a patch adds a new mechanic, the loaders silently ignore the keys describing it and the
+// rules engine quietly stops matching the game. This finds those keys in the raw data.
+
+use crate::data::{KNOWN_AQUASCAPING_STATS, KNOWN_CHEMISTRY_KEYS, KNOWN_SPECIES_STATS, KNOWN_TANK_KEYS};
+use serde_json::Value;
+
+#[derive(Debug, PartialEq)]
+pub struct UnknownKey {
+    /// where the key was found, e.g. `animal.stats`
+    pub section: &'static str,
+    pub key: String,
+    /// every object that has the key
+    pub ids: Vec<String>,
+}
+
+pub fn audit_objects(objects: &[Value]) -> Vec<UnknownKey> {
+    let mut result: Vec<UnknownKey> = Vec::new();
+
+    for o in objects {
+        let id = o["id"].as_str().unwrap_or("unknown");
+
+        let sections: [(&'static str, &Value, &[&str]); 4] = [
+            ("animal.stats", &o["animal"]["stats"], KNOWN_SPECIES_STATS),
+            ("tank", &o["tank"], KNOWN_TANK_KEYS),
+            ("aquascaping.stats", &o["aquascaping"]["stats"], KNOWN_AQUASCAPING_STATS),
+            ("chemistry", &o["chemistry"], KNOWN_CHEMISTRY_KEYS),
+        ];
+
+        for (section, value, known) in sections {
+            let keys = match value.as_object() {
+                Some(map) => map.keys(),
+                None => continue,
+            };
+
+            for key in keys.filter(|k| !known.contains(&k.as_str())) {
+                match result.iter_mut().find(|u| u.section == section && u.key == *key) {
+                    Some(u) => u.ids.push(id.to_string()),
+                    None => result.push(UnknownKey {
+                        section,
+                        key: key.clone(),
+                        ids: vec![id.to_string()],
+                    }),
+                }
+            }
+        }
+    }
+
+    result.sort_by(|a, b| (a.section, &a.key).cmp(&(b.section, &b.key)));
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_unknown_keys_are_grouped_with_ids() {
+        let objects = vec![
+            json!({ "id": "clown", "animal": { "stats": { "isFish": {}, "glows": {}, "sings": {} } } }),
+            json!({ "id": "tang", "animal": { "stats": { "isFish": {}, "glows": {} } } }),
+            json!({ "id": "reef_tank", "tank": { "volumePerTile": 3.5, "isHexagonal": true } }),
+            json!({ "id": "lamp", "chemistry": { "light": 2, "warmth": 1 } }),
+            json!({ "id": "rock", "aquascaping": { "stats": { "isRock": { "value": 1 } }, "size": 2 } }),
+        ];
+
+        let result = audit_objects(&objects);
+        let summary: Vec<_> = result.iter().map(|u| (u.section, u.key.as_str(), u.ids.join(","))).collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("animal.stats", "glows", "clown,tang".to_string()),
+                ("animal.stats", "sings", "clown".to_string()),
+                ("chemistry", "warmth", "lamp".to_string()),
+                ("tank", "isHexagonal", "reef_tank".to_string()),
+            ]
+        );
+    }
+}
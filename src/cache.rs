@@ -0,0 +1,262 @@
+// pattern: Imperative Shell
+
+// Parsing every game data file dominates the runtime of a single invocation, so we keep a bincode
+// snapshot of the parsed GameData around and reuse it as long as none of the source files changed.
+
+use crate::data::{read_game_data, GameData};
+use crate::paths::{game_data_files, language_files, mod_data_files};
+use crate::util::{error, Result};
+
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// Bump this whenever GameData (or anything it contains) changes shape, so old snapshots are
+/// rebuilt instead of misread.
+const CACHE_VERSION: u32 = 11;
+
+const CACHE_FILE: &str = "game-data.bin";
+
+/// What a snapshot was built from. The snapshot is only reused if this matches exactly.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CacheKey {
+    pub directory: PathBuf,
+    pub files: Vec<SourceFile>,
+    /// the mod directories in the order they were applied, with their files
+    pub mods: Vec<(PathBuf, Vec<SourceFile>)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceFile {
+    pub path: String,
+    /// None if the file does not exist
+    pub size: Option<u64>,
+    pub modified: Option<(u64, u32)>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CacheStatus {
+    Missing,
+    Fresh,
+    Stale,
+    OtherVersion(u32),
+    Unreadable(String),
+}
+
+impl std::fmt::Display for CacheStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CacheStatus::Missing => write!(f, "missing"),
+            CacheStatus::Fresh => write!(f, "fresh"),
+            CacheStatus::Stale => write!(f, "stale (game data changed since it was built)"),
+            CacheStatus::OtherVersion(v) => write!(f, "stale (built by cache version {}, current is {})", v, CACHE_VERSION),
+            CacheStatus::Unreadable(e) => write!(f, "unreadable ({})", e),
+        }
+    }
+}
+
+pub fn cache_path() -> Result<PathBuf> {
+    let dirs = ProjectDirs::from("", "", "megaquarium-tools").ok_or(error("cannot determine cache directory"))?;
+    Ok(dirs.cache_dir().join(CACHE_FILE))
+}
+
+pub fn cache_key(directory: &Path, mods: &[PathBuf]) -> CacheKey {
+    let files = game_data_files()
+        .map(|path| path.to_string())
+        .chain(language_files(directory).into_iter().map(|(_, path)| path))
+        .map(|path| source_file(path.clone(), &directory.join(path)))
+        .collect();
+
+    let mods = mods
+        .iter()
+        .map(|m| {
+            let files = mod_data_files(m)
+                .iter()
+                .map(|path| source_file(path.display().to_string(), path))
+                .collect();
+            (m.clone(), files)
+        })
+        .collect();
+
+    CacheKey {
+        directory: directory.to_path_buf(),
+        files,
+        mods,
+    }
+}
+
+fn source_file(path: String, full_path: &Path) -> SourceFile {
+    match fs::metadata(full_path) {
+        Ok(meta) => SourceFile {
+            path,
+            size: Some(meta.len()),
+            modified: meta
+                .modified()
+                .ok()
+                .and_then(|m| m.duration_since(UNIX_EPOCH).ok())
+                .map(|d| (d.as_secs(), d.subsec_nanos())),
+        },
+        Err(_) => SourceFile {
+            path,
+            size: None,
+            modified: None,
+        },
+    }
+}
+
+/// Loads game data from the snapshot if it is fresh, otherwise parses the game files and
+/// refreshes the snapshot. Failing to write the snapshot is not fatal.
+pub fn load_game_data(directory: &Path, mods: &[PathBuf], use_cache: bool) -> Result<GameData> {
+    if !use_cache {
+        return read_game_data(directory, mods);
+    }
+
+    let key = cache_key(directory, mods);
+    let path = cache_path()?;
+
+    if let Ok(Some(data)) = read_snapshot(&path, &key) {
+        return Ok(data);
+    }
+
+    let data = read_game_data(directory, mods)?;
+
+    if let Err(e) = write_snapshot(&path, &key, &data) {
+        eprintln!("warning: cannot write game data cache {}: {}", path.display(), e);
+    }
+
+    Ok(data)
+}
+
+pub fn status(path: &Path, key: &CacheKey) -> CacheStatus {
+    if !path.exists() {
+        return CacheStatus::Missing;
+    }
+
+    let result = fs::File::open(path)
+        .map_err(|e| e.into())
+        .and_then(|f| read_header(&mut BufReader::new(f)));
+
+    match result {
+        Ok((version, _)) if version != CACHE_VERSION => CacheStatus::OtherVersion(version),
+        Ok((_, Some(k))) if k == *key => CacheStatus::Fresh,
+        Ok(_) => CacheStatus::Stale,
+        Err(e) => CacheStatus::Unreadable(e.to_string()),
+    }
+}
+
+pub fn clear(path: &Path) -> Result<bool> {
+    if path.exists() {
+        fs::remove_file(path)?;
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
+/// Reads the version and, if the version matches ours, the key of a snapshot.
+fn read_header<R: std::io::Read>(reader: &mut R) -> Result<(u32, Option<CacheKey>)> {
+    let version: u32 = bincode::deserialize_from(&mut *reader)?;
+    if version != CACHE_VERSION {
+        return Ok((version, None));
+    }
+    let key: CacheKey = bincode::deserialize_from(&mut *reader)?;
+    Ok((version, Some(key)))
+}
+
+fn read_snapshot(path: &Path, key: &CacheKey) -> Result<Option<GameData>> {
+    let mut reader = BufReader::new(fs::File::open(path)?);
+
+    match read_header(&mut reader)? {
+        (CACHE_VERSION, Some(k)) if k == *key => Ok(Some(bincode::deserialize_from(&mut reader)?)),
+        _ => Ok(None),
+    }
+}
+
+fn write_snapshot(path: &Path, key: &CacheKey, data: &GameData) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // write to the side and rename so a concurrent run never sees a half-written snapshot
+    let temporary = path.with_extension(format!("tmp{}", std::process::id()));
+    {
+        let mut writer = BufWriter::new(fs::File::create(&temporary)?);
+        bincode::serialize_into(&mut writer, &CACHE_VERSION)?;
+        bincode::serialize_into(&mut writer, key)?;
+        bincode::serialize_into(&mut writer, data)?;
+        writer.flush()?;
+    }
+    fs::rename(&temporary, path)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::animal::test::test_species;
+    use crate::food::test::test_food;
+    use crate::tank::test::test_tank_model;
+    use crate::util::test::TempDir;
+
+    fn test_key(size: u64) -> CacheKey {
+        CacheKey {
+            directory: PathBuf::from("/games/megaquarium"),
+            files: vec![SourceFile {
+                path: "Data/animals.data".to_string(),
+                size: Some(size),
+                modified: Some((1_700_000_000, 5)),
+            }],
+            mods: vec![],
+        }
+    }
+
+    fn test_data() -> GameData {
+        GameData {
+            species: vec![test_species("clownfish")],
+            tanks: vec![test_tank_model("basic_tank")],
+            food: vec![test_food("flakes")],
+            ..GameData::default()
+        }
+    }
+
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let dir = TempDir::new("cache-roundtrip");
+        let path = dir.path().join("snapshot.bin");
+        write_snapshot(&path, &test_key(10), &test_data()).unwrap();
+
+        assert_eq!(status(&path, &test_key(10)), CacheStatus::Fresh);
+
+        let data = read_snapshot(&path, &test_key(10)).unwrap().unwrap();
+        assert_eq!(data.species, test_data().species);
+        assert_eq!(data.tanks[0].id, "basic_tank");
+        assert_eq!(data.food, vec![test_food("flakes")]);
+
+        assert!(clear(&path).unwrap());
+        assert_eq!(status(&path, &test_key(10)), CacheStatus::Missing);
+    }
+
+    #[test]
+    fn test_snapshot_is_stale_when_sources_change() {
+        let dir = TempDir::new("cache-stale");
+        let path = dir.path().join("snapshot.bin");
+        write_snapshot(&path, &test_key(10), &test_data()).unwrap();
+
+        assert_eq!(status(&path, &test_key(11)), CacheStatus::Stale);
+        assert!(read_snapshot(&path, &test_key(11)).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_snapshot_from_other_version_is_ignored() {
+        let dir = TempDir::new("cache-version");
+        let path = dir.path().join("snapshot.bin");
+        fs::write(&path, bincode::serialize(&(CACHE_VERSION + 1)).unwrap()).unwrap();
+
+        assert_eq!(status(&path, &test_key(10)), CacheStatus::OtherVersion(CACHE_VERSION + 1));
+        assert!(read_snapshot(&path, &test_key(10)).unwrap().is_none());
+    }
+}
@@ -3,9 +3,12 @@
 use crate::animal::*;
 use crate::aquarium::*;
 use crate::data::{self, GameData};
+use crate::economy::ExhibitEconomy;
+use crate::equipment::LifeSupport;
 use crate::rules::*;
 use crate::tank::*;
 use crate::util::*;
+use crate::water::WaterQuality;
 
 pub struct CheckArgs<'a> {
     pub species: &'a [SpeciesCount],
@@ -22,6 +25,8 @@ pub struct CheckQuery<'a> {
 pub struct ExhibitCheckResult {
     pub violations: Vec<Violation>,
     pub food: Vec<FoodAmount>,
+    /// of the animals and their food only, since there's no particular tank
+    pub economy: ExhibitEconomy,
     pub minimum_viable_environment: Environment,
 }
 
@@ -35,9 +40,14 @@ impl ExhibitCheckResult {
 pub struct ExhibitValidation {
     pub name: String,
     pub loaded_environment: LoadedEnvironment,
+    pub life_support: LifeSupport,
+    /// None if the exhibit has no filtration we know about
+    pub water: Option<WaterQuality>,
     pub minimum_viable_environment: Environment,
     pub food: Vec<FoodAmount>,
+    pub economy: ExhibitEconomy,
     pub violations: Vec<Violation>,
+    pub location: Location,
 }
 
 #[derive(Debug)]
@@ -67,14 +77,32 @@ pub fn validate_aquarium(data: &data::GameData, args: &ValidateArgs) -> Aquarium
             environment: minimum_viable_environment,
         };
 
-        let violations = find_violations(&exhibit_spec);
+        let mut violations = find_violations(&exhibit_spec);
+
+        let life_support = exhibit.life_support();
+        let water = WaterQuality::of(&exhibit.animals, &life_support);
+        if let Some(w) = &water {
+            violations.extend(find_water_quality_violations(&exhibit.animals, w));
+        }
+
+        let items = exhibit
+            .animals
+            .iter()
+            .map(|a| &a.species.economy)
+            .chain(std::iter::once(&exhibit.tank.model.economy))
+            .chain(exhibit.fixtures.iter().map(|f| &f.model.economy));
+        let economy = ExhibitEconomy::of(items, food_cost(&food));
 
         exhibits.push(ExhibitValidation {
             name: exhibit.name.clone(),
             loaded_environment: exhibit.loaded_environment(),
+            life_support,
+            water,
             minimum_viable_environment,
             food,
+            economy,
             violations,
+            location: exhibit.location.clone(),
         });
     }
 
@@ -86,10 +114,12 @@ pub fn check_for_viable_tank<'a>(data: &GameData, animals: &[AnimalRef]) -> Exhi
     let exhibit = ExhibitSpec { animals, environment };
     let violations = find_violations(&exhibit);
     let food = minimum_required_food(data, &exhibit.animals);
+    let economy = ExhibitEconomy::of(animals.iter().map(|a| &a.species.economy), food_cost(&food));
 
     ExhibitCheckResult {
         violations,
         food,
+        economy,
         minimum_viable_environment: environment,
     }
 }
@@ -207,6 +237,14 @@ pub struct FoodAmount {
     pub food: String,
     pub count: u16,
     pub skill: u8,
+    pub storage: Option<String>,
+    pub preparation: Option<String>,
+    /// cost of `count` portions
+    pub cost: Option<u32>,
+}
+
+fn food_cost(food: &[FoodAmount]) -> u32 {
+    food.iter().filter_map(|f| f.cost).sum()
 }
 
 fn minimum_required_food(data: &GameData, species: &[AnimalRef<'_>]) -> Vec<FoodAmount> {
@@ -220,7 +258,8 @@ fn minimum_required_food(data: &GameData, species: &[AnimalRef<'_>]) -> Vec<Food
 
     data.food
         .iter()
-        .filter_map(|food| {
+        .filter_map(|model| {
+            let food = &model.id;
             let count = diets.iter().filter_map(|(x, c, _)| if food == *x { Some(c) } else { None }).sum();
             let skill = diets
                 .iter()
@@ -232,6 +271,9 @@ fn minimum_required_food(data: &GameData, species: &[AnimalRef<'_>]) -> Vec<Food
                     food: food.clone(),
                     count,
                     skill,
+                    storage: model.storage.clone(),
+                    preparation: model.preparation.clone(),
+                    cost: model.cost.map(|c| c * count as u32),
                 })
             } else {
                 None
@@ -264,7 +306,7 @@ fn minimum_loves<F: Fn(&Species) -> Option<u8>>(list: &[AnimalRef], f: F) -> Opt
 }
 
 /// Pure lookup: searches the in-memory species list by fuzzy match.
-fn lookup<'a>(data: &'a data::GameData, species: &str) -> Result<&'a Species> {
+pub fn lookup<'a>(data: &'a data::GameData, species: &str) -> Result<&'a Species> {
     let possible = data.species_search(species);
 
     if possible.len() == 0 {
@@ -282,6 +324,9 @@ mod test {
     use super::*;
     use crate::animal::test::test_species;
     use crate::animal::Growth;
+    use crate::equipment::test::test_equipment_model;
+    use crate::equipment::{EquipmentModel, EquipmentRef};
+    use crate::food::test::test_food;
     use crate::tank::test::test_tank_model;
 
     #[test]
@@ -294,8 +339,7 @@ mod test {
         let data = GameData {
             species: vec![species1.clone(), species2.clone()],
             tanks: vec![tank_model.clone()],
-            fixtures: vec![],
-            food: vec![],
+            ..GameData::default()
         };
 
         let animals = vec![
@@ -322,9 +366,14 @@ mod test {
             tank: tank_ref,
             animals,
             fixtures: vec![],
+            equipment: vec![],
+            location: Default::default(),
         };
 
-        let aquarium = AquariumRef { exhibits: vec![exhibit] };
+        let aquarium = AquariumRef {
+            exhibits: vec![exhibit],
+            numbered_animals: Default::default(),
+        };
 
         let args = ValidateArgs { aquarium: &aquarium };
 
@@ -348,8 +397,7 @@ mod test {
         let data = GameData {
             species: vec![warm_species.clone(), cold_species.clone()],
             tanks: vec![tank_model.clone()],
-            fixtures: vec![],
-            food: vec![],
+            ..GameData::default()
         };
 
         let animals = vec![
@@ -376,9 +424,14 @@ mod test {
             tank: tank_ref,
             animals,
             fixtures: vec![],
+            equipment: vec![],
+            location: Default::default(),
         };
 
-        let aquarium = AquariumRef { exhibits: vec![exhibit] };
+        let aquarium = AquariumRef {
+            exhibits: vec![exhibit],
+            numbered_animals: Default::default(),
+        };
 
         let args = ValidateArgs { aquarium: &aquarium };
 
@@ -403,8 +456,7 @@ mod test {
         let data = GameData {
             species: vec![salty_species.clone(), freshwater_species.clone()],
             tanks: vec![tank_model.clone()],
-            fixtures: vec![],
-            food: vec![],
+            ..GameData::default()
         };
 
         let animals = vec![
@@ -431,9 +483,14 @@ mod test {
             tank: tank_ref,
             animals,
             fixtures: vec![],
+            equipment: vec![],
+            location: Default::default(),
         };
 
-        let aquarium = AquariumRef { exhibits: vec![exhibit] };
+        let aquarium = AquariumRef {
+            exhibits: vec![exhibit],
+            numbered_animals: Default::default(),
+        };
 
         let args = ValidateArgs { aquarium: &aquarium };
 
@@ -464,10 +521,8 @@ mod test {
         let tank_model = test_tank_model("empty_tank");
 
         let data = GameData {
-            species: vec![],
             tanks: vec![tank_model.clone()],
-            fixtures: vec![],
-            food: vec![],
+            ..GameData::default()
         };
 
         let tank_ref = TankRef {
@@ -481,9 +536,14 @@ mod test {
             tank: tank_ref,
             animals: vec![],
             fixtures: vec![],
+            equipment: vec![],
+            location: Default::default(),
         };
 
-        let aquarium = AquariumRef { exhibits: vec![exhibit] };
+        let aquarium = AquariumRef {
+            exhibits: vec![exhibit],
+            numbered_animals: Default::default(),
+        };
         let args = ValidateArgs { aquarium: &aquarium };
 
         let result = validate_aquarium(&data, &args);
@@ -496,6 +556,63 @@ mod test {
         assert_eq!(result.exhibits[0].minimum_viable_environment, Environment::default());
     }
 
+    #[test]
+    fn test_validate_aquarium_water_quality_from_equipment() {
+        let mut picky = test_species("picky");
+        picky.habitat.minimum_quality = 80;
+
+        let data = GameData {
+            species: vec![picky],
+            tanks: vec![test_tank_model("basic_tank")],
+            ..GameData::default()
+        };
+        let weak = EquipmentModel {
+            filtration: Some(6),
+            ..test_equipment_model("weak_filter")
+        };
+        let strong = EquipmentModel {
+            filtration: Some(60),
+            ..test_equipment_model("strong_filter")
+        };
+
+        let validate = |filter: &EquipmentModel| {
+            let exhibit = ExhibitRef {
+                name: "Filtered".to_string(),
+                tank: TankRef {
+                    id: 1,
+                    model: &data.tanks[0],
+                    size: (5, 5),
+                },
+                animals: (1..=2)
+                    .map(|id| AnimalRef {
+                        id,
+                        species: &data.species[0],
+                        growth: Growth::Final,
+                    })
+                    .collect(),
+                fixtures: vec![],
+                equipment: vec![EquipmentRef { id: 3, model: filter }],
+                location: Default::default(),
+            };
+            let aquarium = AquariumRef {
+                exhibits: vec![exhibit],
+                numbered_animals: Default::default(),
+            };
+            validate_aquarium(&data, &ValidateArgs { aquarium: &aquarium }).exhibits.remove(0)
+        };
+
+        let is_filtration = |v: &Violation| matches!(v.constraint, Constraint::Filtration { .. });
+
+        // two animals of size 5 make a bioload of 10
+        let result = validate(&weak);
+        assert_eq!(result.water.map(|w| w.achievable), Some(60));
+        assert_eq!(result.violations.iter().filter(|v| is_filtration(v)).count(), 2);
+
+        let result = validate(&strong);
+        assert_eq!(result.water.map(|w| w.achievable), Some(100));
+        assert!(!result.violations.iter().any(is_filtration));
+    }
+
     #[test]
     fn test_minimum_viable_tank_empty() {
         let result = minimum_viable_tank(&[]);
@@ -504,12 +621,7 @@ mod test {
 
     #[test]
     fn test_check_for_viable_tank_empty() {
-        let data = GameData {
-            species: vec![],
-            tanks: vec![],
-            fixtures: vec![],
-            food: vec![],
-        };
+        let data = GameData::default();
 
         let result = check_for_viable_tank(&data, &[]);
 
@@ -527,8 +639,7 @@ mod test {
         let data = GameData {
             species: vec![species.clone()],
             tanks: vec![tank_model.clone()],
-            fixtures: vec![],
-            food: vec![],
+            ..GameData::default()
         };
 
         let tank_ref1 = TankRef {
@@ -547,6 +658,8 @@ mod test {
             tank: tank_ref1,
             animals: vec![],
             fixtures: vec![],
+            equipment: vec![],
+            location: Default::default(),
         };
 
         let populated_exhibit = ExhibitRef {
@@ -558,10 +671,13 @@ mod test {
                 growth: Growth::Final,
             }],
             fixtures: vec![],
+            equipment: vec![],
+            location: Default::default(),
         };
 
         let aquarium = AquariumRef {
             exhibits: vec![empty_exhibit, populated_exhibit],
+            numbered_animals: Default::default(),
         };
         let args = ValidateArgs { aquarium: &aquarium };
 
@@ -597,8 +713,8 @@ mod test {
         let data = GameData {
             species: vec![species1.clone(), species2.clone()],
             tanks: vec![tank_model.clone()],
-            fixtures: vec![],
-            food: vec!["flakes".to_string()],
+            food: vec![test_food("flakes")],
+            ..GameData::default()
         };
 
         let animals = vec![
@@ -636,8 +752,8 @@ mod test {
         let data = GameData {
             species: vec![species.clone()],
             tanks: vec![tank_model.clone()],
-            fixtures: vec![],
-            food: vec!["flakes".to_string()],
+            food: vec![test_food("flakes")],
+            ..GameData::default()
         };
 
         let animals = vec![AnimalRef {
@@ -675,8 +791,8 @@ mod test {
         let data = GameData {
             species: vec![species1.clone(), species2.clone()],
             tanks: vec![tank_model.clone()],
-            fixtures: vec![],
-            food: vec!["flakes".to_string(), "krill".to_string()],
+            food: vec![test_food("flakes"), test_food("krill")],
+            ..GameData::default()
         };
 
         let animals = vec![
@@ -0,0 +1,183 @@
+// pattern: Functional Core
+
+// Puts what the game says about each animal next to what our rules say, to find rules the engine
+// is missing (the game complains and we don't) or getting wrong (we complain and the game doesn't).
+
+use std::collections::HashMap;
+
+use crate::animal::{AnimalId, AnimalState};
+use crate::aquarium::AquariumRef;
+use crate::check::AquariumCheckResult;
+use crate::rules::Violation;
+
+/// Health or happiness under this percentage counts as the game complaining about an animal, even
+/// if it doesn't name an unmet need.
+pub const STRUGGLING_BELOW: u8 = 50;
+
+/// Whether the game thinks something is wrong with the animal.
+pub fn game_complains(state: &AnimalState) -> bool {
+    let struggling = |p: Option<u8>| p.is_some_and(|p| p < STRUGGLING_BELOW);
+    !state.unmet_needs.is_empty() || struggling(state.health) || struggling(state.happiness)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Agreement {
+    Both,
+    OnlyGame,
+    OnlyOurs,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AnimalComparison {
+    pub animal: AnimalId,
+    pub species: String,
+    pub state: AnimalState,
+    /// our violations about this animal
+    pub ours: Vec<Violation>,
+}
+
+impl AnimalComparison {
+    pub fn agreement(&self) -> Agreement {
+        match (game_complains(&self.state), self.ours.is_empty()) {
+            (true, false) => Agreement::Both,
+            (true, true) => Agreement::OnlyGame,
+            _ => Agreement::OnlyOurs,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ExhibitComparison {
+    pub exhibit: String,
+    /// only the animals the game or we have something to say about
+    pub animals: Vec<AnimalComparison>,
+}
+
+/// `result` has to be the validation of `aquarium`, so its exhibits line up.
+pub fn compare_with_game(
+    aquarium: &AquariumRef,
+    states: &HashMap<AnimalId, AnimalState>,
+    result: &AquariumCheckResult,
+) -> Vec<ExhibitComparison> {
+    aquarium
+        .exhibits
+        .iter()
+        .zip(&result.exhibits)
+        .map(|(exhibit, validation)| {
+            let animals = exhibit
+                .animals
+                .iter()
+                .filter_map(|a| {
+                    let mut ours: Vec<Violation> = Vec::new();
+                    let about_animal = |v: &&Violation| v.animal.id == a.id || v.conflicting.as_ref().is_some_and(|c| c.id == a.id);
+                    for v in validation.violations.iter().filter(about_animal) {
+                        if !ours.contains(v) {
+                            ours.push(v.clone());
+                        }
+                    }
+
+                    let state = states.get(&a.id).cloned().unwrap_or_default();
+                    if ours.is_empty() && !game_complains(&state) {
+                        return None;
+                    }
+
+                    Some(AnimalComparison {
+                        animal: a.id,
+                        species: a.species.id.clone(),
+                        state,
+                        ours,
+                    })
+                })
+                .collect();
+
+            ExhibitComparison {
+                exhibit: exhibit.name.clone(),
+                animals,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::animal::test::test_species;
+    use crate::animal::{AnimalRef, Growth};
+    use crate::aquarium::ExhibitRef;
+    use crate::check::{validate_aquarium, ValidateArgs};
+    use crate::data::GameData;
+    use crate::tank::test::test_tank_model;
+    use crate::tank::{TankRef, Temperature};
+
+    #[test]
+    fn test_compare_with_game() {
+        let mut cold = test_species("cold_fish");
+        cold.habitat.temperature = Temperature::Cold;
+        let warm = test_species("warm_fish");
+        let calm = test_species("calm_fish");
+        let tank = test_tank_model("basic_tank");
+        let data = GameData::default();
+
+        let animal = |id, species| AnimalRef {
+            id,
+            species,
+            growth: Growth::Final,
+        };
+        let exhibit = |name: &str, id, animals| ExhibitRef {
+            name: name.to_string(),
+            tank: TankRef {
+                id,
+                model: &tank,
+                size: (3, 3),
+            },
+            animals,
+            fixtures: vec![],
+            equipment: vec![],
+            location: Default::default(),
+        };
+        let aquarium = AquariumRef {
+            exhibits: vec![
+                exhibit("Reef", 1, vec![animal(10, &cold), animal(11, &warm)]),
+                exhibit("Pond", 2, vec![animal(12, &calm), animal(13, &warm)]),
+            ],
+            numbered_animals: Default::default(),
+        };
+        let result = validate_aquarium(&data, &ValidateArgs { aquarium: &aquarium });
+
+        let unhappy = AnimalState {
+            health: Some(90),
+            happiness: Some(30),
+            unmet_needs: vec!["needsDecoration".to_string()],
+        };
+        // nothing named, but far from happy
+        let miserable = AnimalState {
+            health: Some(100),
+            happiness: Some(20),
+            unmet_needs: vec![],
+        };
+        let states = HashMap::from([(10, AnimalState::default()), (12, unhappy.clone()), (13, miserable)]);
+
+        let comparison = compare_with_game(&aquarium, &states, &result);
+        let summary: Vec<Vec<_>> = comparison
+            .iter()
+            .map(|e| e.animals.iter().map(|a| (a.animal, a.agreement())).collect())
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                vec![(10, Agreement::OnlyOurs), (11, Agreement::OnlyOurs)],
+                vec![(12, Agreement::OnlyGame), (13, Agreement::OnlyGame)],
+            ]
+        );
+        assert_eq!(comparison[1].animals[0].state, unhappy);
+
+        let healthy = AnimalState {
+            health: Some(STRUGGLING_BELOW),
+            happiness: Some(100),
+            unmet_needs: vec![],
+        };
+        assert!(!game_complains(&healthy));
+        assert!(!game_complains(&AnimalState::default()));
+    }
+}
@@ -2,29 +2,57 @@
 
 use crate::animal::*;
 use crate::aquarium::*;
+use crate::dlc::{Dlc, DlcSelection};
+use crate::economy::Economy;
+use crate::equipment::{EquipmentModel, EquipmentRef};
 use crate::fixture::*;
+use crate::food::FoodModel;
+use crate::names::Names;
+use crate::park::{ParkState, StaffMember};
 use crate::paths::*;
+use crate::progress::Progress;
 use crate::tank::*;
 use crate::util::error;
 use crate::util::Result;
 
-use lazy_static::lazy_static;
-use regex::Regex;
-use serde_json::{from_str, Map, Value};
-use std::collections::HashMap;
+use crate::json;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::fmt;
 use std::fs;
-use std::path::Path;
-
+use std::path::{Path, PathBuf};
+
+/// Everything the game and any loaded mods define. Each species, tank, fixture, piece of equipment
+/// and food also records:
+/// - `mod_name`: the mod that defined or last overrode it, None if unmodded
+/// - `dlc`: the DLC it comes with, None for base game content
+/// - `unlock_level`: the research level that unlocks it, None if available from the start (species,
+///   tanks and fixtures only)
+/// - `names`: its display names from the game's string files
+#[derive(Default, Serialize, Deserialize)]
 pub struct GameData {
     pub species: Vec<Species>,
     pub tanks: Vec<TankModel>,
     pub fixtures: Vec<FixtureModel>,
-    pub food: Vec<String>,
+    pub equipment: Vec<EquipmentModel>,
+    pub food: Vec<FoodModel>,
+    /// objects from the fixture files that aren't fixtures, with the reason
+    pub skipped_fixtures: Vec<SkippedFixture>,
 }
 
 impl GameData {
+    /// Game data with just these species, for tests.
+    #[cfg(test)]
+    pub fn with_species(species: Vec<Species>) -> GameData {
+        GameData {
+            species,
+            ..GameData::default()
+        }
+    }
+
     pub fn try_species_ref(&self, id: &str) -> Option<&Species> {
         for s in &self.species {
             if s.id.eq(id) {
@@ -41,9 +69,12 @@ impl GameData {
 
     pub fn species_search(&self, search_string: &str) -> Vec<&Species> {
         fn is_adult(s: &Species) -> bool {
-            s.breeding != Breeding::NotFullyGrown
+            !matches!(s.breeding, Breeding::NotFullyGrown(_))
+        }
+        fn matches(s: &Species, parts: &[&str]) -> bool {
+            parts.iter().all(|p| s.id.contains(p)) || s.names.matches(parts)
         }
-        fuzzy_match_string(|s: &Species| &s.id, is_adult, search_string, self.species.as_slice())
+        fuzzy_match(matches, is_adult, search_string, self.species.as_slice())
     }
 
     pub fn try_tank_ref(&self, id: &str) -> Option<&TankModel> {
@@ -67,16 +98,87 @@ impl GameData {
     pub fn fixture_ref(&self, id: &str) -> Result<&FixtureModel> {
         self.try_fixture_ref(id).ok_or(error(format!("unknown fixture {}", id)))
     }
+
+    pub fn try_equipment_ref(&self, id: &str) -> Option<&EquipmentModel> {
+        self.equipment.iter().find(|e| e.id.eq(id))
+    }
+
+    pub fn equipment_ref(&self, id: &str) -> Result<&EquipmentModel> {
+        self.try_equipment_ref(id).ok_or(error(format!("unknown equipment {}", id)))
+    }
+
+    /// Drops everything that comes from DLC the selection doesn't allow.
+    pub fn restrict_to(mut self, selection: &DlcSelection) -> GameData {
+        self.species.retain(|s| selection.allows(s.dlc));
+        self.tanks.retain(|t| selection.allows(t.dlc));
+        self.fixtures.retain(|f| selection.allows(f.dlc));
+        self.equipment.retain(|e| selection.allows(e.dlc));
+        self.food.retain(|f| selection.allows(f.dlc));
+        self
+    }
+
+    /// Drops everything the player hasn't unlocked yet.
+    pub fn restrict_to_unlocked(mut self, progress: &Progress) -> GameData {
+        self.species.retain(|s| progress.allows(&s.id, s.unlock_level));
+        self.tanks.retain(|t| progress.allows(&t.id, t.unlock_level));
+        self.fixtures.retain(|f| progress.allows(&f.id, f.unlock_level));
+        self
+    }
+
+    /// Gives everything the display names the string files have for its id.
+    pub fn apply_names(&mut self, names: &HashMap<String, Names>) {
+        let name = |id: &String| names.get(id).cloned().unwrap_or_default();
+        self.species.iter_mut().for_each(|s| s.names = name(&s.id));
+        self.tanks.iter_mut().for_each(|t| t.names = name(&t.id));
+        self.fixtures.iter_mut().for_each(|f| f.names = name(&f.id));
+        self.equipment.iter_mut().for_each(|e| e.names = name(&e.id));
+        self.food.iter_mut().for_each(|f| f.names = name(&f.id));
+    }
+
+    /// Every language anything has a name in.
+    pub fn languages(&self) -> Vec<&str> {
+        let species = self.species.iter().flat_map(|s| s.names.languages());
+        let tanks = self.tanks.iter().flat_map(|t| t.names.languages());
+        let fixtures = self.fixtures.iter().flat_map(|f| f.names.languages());
+        let equipment = self.equipment.iter().flat_map(|e| e.names.languages());
+        let food = self.food.iter().flat_map(|f| f.names.languages());
+
+        let mut result: Vec<&str> = species.chain(tanks).chain(fixtures).chain(equipment).chain(food).collect();
+        result.sort_unstable();
+        result.dedup();
+        result
+    }
+
+    /// Layers a mod's content over this data. Anything with an id we already have is replaced in
+    /// place (keeping the DLC it came from), anything new is added.
+    pub fn apply_mod(&mut self, layer: GameData) {
+        override_by_id(&mut self.species, layer.species, |s| &s.id, |s| &mut s.dlc);
+        override_by_id(&mut self.tanks, layer.tanks, |t| &t.id, |t| &mut t.dlc);
+        override_by_id(&mut self.fixtures, layer.fixtures, |f| &f.id, |f| &mut f.dlc);
+        override_by_id(&mut self.equipment, layer.equipment, |e| &e.id, |e| &mut e.dlc);
+        override_by_id(&mut self.food, layer.food, |f| &f.id, |f| &mut f.dlc);
+    }
 }
 
-fn fuzzy_match_string<'a, T, F: Fn(&T) -> &str, P: Fn(&T) -> bool>(f: F, predicate: P, search_string: &str, list: &'a [T]) -> Vec<&'a T> {
+fn override_by_id<T>(existing: &mut Vec<T>, layer: Vec<T>, id: fn(&T) -> &String, dlc: fn(&mut T) -> &mut Option<Dlc>) {
+    for mut x in layer {
+        match existing.iter().position(|e| id(e) == id(&x)) {
+            Some(i) => {
+                *dlc(&mut x) = *dlc(&mut existing[i]);
+                existing[i] = x;
+            }
+            None => existing.push(x),
+        }
+    }
+}
+
+fn fuzzy_match<'a, T, F: Fn(&T, &[&str]) -> bool, P: Fn(&T) -> bool>(f: F, predicate: P, search_string: &str, list: &'a [T]) -> Vec<&'a T> {
     let mut result = Vec::new();
 
     let parts: Vec<&str> = search_string.split(" ").collect();
 
     for x in list {
-        let name = f(x);
-        if predicate(x) && parts.iter().all(|p| name.contains(p)) {
+        if predicate(x) && f(x, &parts) {
             result.push(x);
         }
     }
@@ -84,41 +186,322 @@ fn fuzzy_match_string<'a, T, F: Fn(&T) -> &str, P: Fn(&T) -> bool>(f: F, predica
     result
 }
 
-pub fn read_game_data() -> Result<GameData> {
-    let directory = find_data_dir();
+/// Reads the base game plus every installed DLC, tagging each entity with where it came from, then
+/// layers the given mod directories on top in order.
+pub fn read_game_data(directory: &Path, mods: &[PathBuf]) -> Result<GameData> {
+    let mut result = GameData {
+        species: Vec::new(),
+        tanks: Vec::new(),
+        fixtures: Vec::new(),
+        equipment: Vec::new(),
+        food: Vec::new(),
+        skipped_fixtures: Vec::new(),
+    };
+
+    let sources = std::iter::once((None, &BASE_GAME_FILES)).chain(installed_dlc(directory).into_iter().map(|d| (Some(d), dlc_files(d))));
+
+    for (dlc, files) in sources {
+        result.species.extend(read_species(directory, files.animals, dlc)?);
+        result.tanks.extend(read_tank_models(directory, files.tanks, dlc)?);
+        result.fixtures.extend(read_fixture_models(directory, files.fixtures, dlc)?);
+        result.skipped_fixtures.extend(read_skipped_fixtures(directory, files.fixtures)?);
+        result.equipment.extend(read_equipment_models(directory, files.fixtures, dlc)?);
+        result.food.extend(read_food(directory, files.food, dlc)?);
+    }
+
+    for m in mods {
+        result.apply_mod(read_mod(m)?);
+    }
+
+    // after the mods, so anything a mod overrides keeps its name
+    result.apply_names(&read_names(directory)?);
+    link_life_cycles(&mut result.species);
+
+    Ok(result)
+}
+
+/// The game data doesn't always say what an egg or fry grows into, but the ids follow a pattern:
+/// `x.egg` hatches into `x.fry` if there is one and `x` otherwise, and `x.fry` grows into `x`.
+fn link_life_cycles(species: &mut [Species]) {
+    let ids: Vec<String> = species.iter().map(|s| s.id.clone()).collect();
+
+    for s in species.iter_mut() {
+        if let Breeding::NotFullyGrown(young) = &mut s.breeding {
+            if young.grows_into.is_some() {
+                continue;
+            }
+
+            let candidates = if let Some(stem) = s.id.strip_suffix(".egg") {
+                vec![format!("{}.fry", stem), stem.to_string()]
+            } else if let Some(stem) = s.id.strip_suffix(".fry") {
+                vec![stem.to_string()]
+            } else {
+                vec![]
+            };
+
+            young.grows_into = candidates.into_iter().find(|c| ids.contains(c));
+        }
+    }
+}
 
-    let result = GameData {
-        species: read_species(&directory)?,
-        tanks: read_tank_models(&directory)?,
-        fixtures: read_fixture_models(&directory)?,
-        food: read_food(&directory)?,
+/// Reads every `.data` file in a mod directory. Mods don't follow the base game's file layout, so
+/// each object is classified by what it contains rather than by which file it's in.
+fn read_mod(mod_dir: &Path) -> Result<GameData> {
+    let name = mod_name(mod_dir);
+    let mut result = GameData {
+        species: Vec::new(),
+        tanks: Vec::new(),
+        fixtures: Vec::new(),
+        equipment: Vec::new(),
+        food: Vec::new(),
+        skipped_fixtures: Vec::new(),
     };
 
+    // paths are relative to the mod's parent so errors name the mod, e.g. "987654/fish.data"
+    let root = mod_dir.parent().unwrap_or(mod_dir);
+
+    for path in mod_data_files(mod_dir) {
+        let file = path.strip_prefix(root).unwrap_or(&path).to_string_lossy().to_string();
+        let json = read_json(root, &file)?;
+
+        for (i, o) in objects(&json, &file)?.iter().enumerate() {
+            in_object(&file, i, o, read_mod_object(o, &name, &mut result))?;
+        }
+    }
+
     Ok(result)
 }
 
+fn read_mod_object(o: &Value, name: &str, result: &mut GameData) -> Result<()> {
+    let obj = o.as_object().ok_or(bad_json("object is not json object"))?;
+    let mod_name = Some(name.to_string());
+
+    if obj.contains_key("animal") {
+        if let Some(s) = read_single_species(o)? {
+            result.species.push(Species { mod_name, ..s });
+        }
+    } else if obj.contains_key("tank") {
+        let t = read_single_tank_model(o)?;
+        result.tanks.push(TankModel { mod_name, ..t });
+    } else if let Some(f) = read_single_food(o)? {
+        result.food.push(FoodModel { mod_name, ..f });
+    } else {
+        // lights are both a fixture and equipment
+        if let Some(f) = read_single_fixture_model(o)? {
+            result.fixtures.push(FixtureModel {
+                mod_name: mod_name.clone(),
+                ..f
+            });
+        }
+        if let Some(e) = read_single_equipment_model(o)? {
+            result.equipment.push(EquipmentModel { mod_name, ..e });
+        }
+    }
+
+    Ok(())
+}
+
+/// Workshop mods live in directories named after their numeric id, so the name is just the
+/// directory name.
+fn mod_name(mod_dir: &Path) -> String {
+    match mod_dir.file_name() {
+        Some(n) => n.to_string_lossy().to_string(),
+        None => mod_dir.display().to_string(),
+    }
+}
+
 pub fn read_hosting_tank(o: &Value, id: u64) -> Result<u64> {
     o["hosting"]["host"]
         .as_u64()
         .ok_or(Box::new(bad_json(format!("no host id for {}", id))))
 }
 
-pub fn read_save<'a>(data: &'a GameData, save_name: &str) -> Result<AquariumRef<'a>> {
-    let directory = find_save_dir();
-    let json = read_json(&directory, &(save_name.to_string() + ".sav"))?;
+/// Splits a save path into the directory and file name, so errors name the save file rather than
+/// the whole path.
+fn split_save_path(path: &Path) -> (&Path, String) {
+    let directory = path.parent().unwrap_or(Path::new(""));
+    let file = match path.file_name() {
+        Some(f) => f.to_string_lossy().to_string(),
+        None => path.display().to_string(),
+    };
+    (directory, file)
+}
 
-    let objects = json["objects"].as_array().ok_or("no objects")?;
+/// Counts the exhibit tanks in a save without reading anything else, which is much quicker than
+/// `read_save` and doesn't need the game data.
+pub fn count_exhibits(path: &Path) -> Result<usize> {
+    let (directory, file) = split_save_path(path);
+    let json = read_json(directory, &file)?;
+    let objects = objects(&json, &file)?;
+
+    let is_exhibit = |o: &&Value| o["inGameWorld"] == Value::Bool(true) && o.get("tank").is_some();
+    Ok(objects.iter().filter(is_exhibit).count())
+}
 
-    let mut animals: HashMap<u64, Vec<AnimalRef<'a>>> = HashMap::new();
-    let mut tanks: Vec<(String, TankRef)> = Vec::new();
+/// Reads only the player's research progress, which doesn't need the game data, so the data can
+/// be restricted to what's unlocked before anything else uses it.
+pub fn read_progress(path: &Path) -> Result<Progress> {
+    let (directory, file) = split_save_path(path);
+    let json = read_json(directory, &file)?;
+    let research = json["research"]
+        .as_object()
+        .ok_or_else(|| file_error(&file, "no research progress"))?;
+
+    let level = uint_or_default(&json["research"]["level"], 0)?;
+    let unlocked = match research.get("unlocked") {
+        None => Vec::new(),
+        Some(v) => as_string_array(v)?.into_iter().map(|s| s.to_string()).collect(),
+    };
+
+    Ok(Progress { level, unlocked })
+}
+
+#[derive(Debug)]
+pub struct Save<'a> {
+    pub aquarium: AquariumRef<'a>,
+    pub park: ParkState,
+    /// what the game thinks of each animal
+    pub animal_states: HashMap<AnimalId, AnimalState>,
+    /// animals in holding tanks, being carried and the like, by uid; never `Hosting::Exhibit`
+    pub non_exhibit_animals: Vec<(AnimalRef<'a>, Hosting)>,
+    /// what couldn't be read; only unmodeled fixtures unless the save was read leniently
+    pub diagnostics: Vec<SaveDiagnostic>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UnmodeledFixture {
+    pub uid: u64,
+    pub spec_id: String,
+    pub tank: Option<u64>,
+    pub reason: String,
+}
+
+/// Something in a save that was left out rather than read.
+#[derive(Debug, PartialEq)]
+pub enum SaveDiagnostic {
+    UnknownSpecies {
+        uid: u64,
+        species: String,
+        tank: Option<u64>,
+    },
+    UnknownTankModel {
+        uid: u64,
+        spec_id: String,
+    },
+    /// decoration that has no fixture model, so it doesn't count toward any exhibit
+    UnknownFixture(UnmodeledFixture),
+    MalformedObject {
+        /// position in the save's list of objects
+        index: usize,
+        uid: Option<u64>,
+        tank: Option<u64>,
+        error: String,
+    },
+    /// an exhibit left out of the aquarium because some of its contents couldn't be read, so
+    /// validating it would be misleading
+    IncompleteExhibit {
+        tank: u64,
+        name: String,
+    },
+}
+
+impl SaveDiagnostic {
+    /// The uid of an object that was dropped, so anything hosted by it is somewhere unknown.
+    fn dropped_uid(&self) -> Option<u64> {
+        match self {
+            SaveDiagnostic::UnknownTankModel { uid, .. } => Some(*uid),
+            SaveDiagnostic::MalformedObject { uid, .. } => *uid,
+            _ => None,
+        }
+    }
+
+    /// The tank of an exhibit that's missing something because of this.
+    fn spoiled_tank(&self) -> Option<u64> {
+        match self {
+            SaveDiagnostic::UnknownSpecies { tank, .. } | SaveDiagnostic::MalformedObject { tank, .. } => *tank,
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SaveDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SaveDiagnostic::UnknownSpecies { uid, species, tank } => {
+                write!(f, "animal {} is of unknown species {}", uid, species)?;
+                match tank {
+                    Some(tank) => write!(f, " in tank {}", tank),
+                    None => Ok(()),
+                }
+            }
+            SaveDiagnostic::UnknownTankModel { uid, spec_id } => {
+                write!(f, "tank {} ({}) has no known tank model", uid, spec_id)
+            }
+            SaveDiagnostic::UnknownFixture(x) => match x.tank {
+                Some(tank) => write!(f, "fixture {} ({}) in tank {} isn't modeled: {}", x.uid, x.spec_id, tank, x.reason),
+                None => write!(f, "fixture {} ({}) isn't modeled: {}", x.uid, x.spec_id, x.reason),
+            },
+            SaveDiagnostic::MalformedObject { index, uid, error, .. } => match uid {
+                Some(uid) => write!(f, "object {} cannot be read: {}", uid, error),
+                None => write!(f, "object at index {} cannot be read: {}", index, error),
+            },
+            SaveDiagnostic::IncompleteExhibit { tank, name } => {
+                write!(f, "exhibit {} (tank {}) is left out, not all of it could be read", name, tank)
+            }
+        }
+    }
+}
+
+/// Reads a save, failing on the first thing that can't be read, except for unmodeled fixtures.
+pub fn read_save<'a>(data: &'a GameData, path: &Path) -> Result<Save<'a>> {
+    read_save_with(data, path, false)
+}
+
+/// Reads everything it can from a save, leaving the rest out and describing it in `diagnostics`.
+/// Exhibits with something left out are left out too.
+pub fn read_save_lenient<'a>(data: &'a GameData, path: &Path) -> Result<Save<'a>> {
+    read_save_with(data, path, true)
+}
+
+/// What a single save object adds, kept apart until all of it has been read so an object that
+/// fails partway through leaves nothing behind.
+#[derive(Default)]
+struct SaveObject<'a> {
+    uid: Option<u64>,
+    staff: Option<StaffMember>,
+    guest: bool,
+    /// with the uid of its host
+    animal: Option<(Option<u64>, AnimalRef<'a>, AnimalState)>,
+    /// with the uid of the tank they're in
+    fixture: Option<(u64, FixtureRef<'a>)>,
+    equipment: Option<(u64, EquipmentRef<'a>)>,
+    tank: Option<(String, TankRef<'a>, Location)>,
+    diagnostics: Vec<SaveDiagnostic>,
+}
+
+fn read_save_with<'a>(data: &'a GameData, path: &Path, lenient: bool) -> Result<Save<'a>> {
+    let (directory, file) = split_save_path(path);
+    let json = read_json(directory, &file)?;
+    let objects = objects(&json, &file)?;
+
+    // with the uid of their host, which could turn out not to be an exhibit
+    let mut animals: Vec<(Option<u64>, AnimalRef<'a>)> = Vec::new();
+    // every uid in the game world, to tell holding tanks from hosts that aren't there
+    let mut uids: HashSet<u64> = HashSet::new();
+    let mut tanks: Vec<(String, TankRef, Location)> = Vec::new();
     let mut fixtures: HashMap<u64, Vec<FixtureRef<'a>>> = HashMap::new();
+    let mut equipment: HashMap<u64, Vec<EquipmentRef<'a>>> = HashMap::new();
+    let mut diagnostics: Vec<SaveDiagnostic> = Vec::new();
+    let mut park = read_park(&json, &file)?;
+    let mut animal_states: HashMap<AnimalId, AnimalState> = HashMap::new();
 
     // sort the tank models by length of id so we always choose the longest prefix
     let mut models: Vec<&'a TankModel> = data.tanks.iter().map(|t| t).collect();
     models.sort_unstable_by_key(|t| -(t.id.len() as i32));
 
-    for o in objects {
+    let read_object = |o: &Value| -> Result<SaveObject<'a>> {
         let obj = o.as_object().ok_or("object is not json object")?;
+        let mut object = SaveObject::default();
 
         let is_in_game_world = match obj.get("inGameWorld") {
             Some(Value::Bool(true)) => true,
@@ -126,51 +509,87 @@ pub fn read_save<'a>(data: &'a GameData, save_name: &str) -> Result<AquariumRef<
         };
 
         if !is_in_game_world {
-            continue;
+            return Ok(object);
+        }
+
+        object.uid = o["uid"].as_u64();
+
+        if let Some(s) = obj.get("staff") {
+            object.staff = Some(read_staff_member(o, s)?);
         }
 
+        object.guest = obj.contains_key("guest");
+
         if let Some(a) = obj.get("animal") {
-            let id = o["uid"].as_u64().ok_or("no id")?;
+            let id = o["uid"].as_u64().ok_or("no uid")?;
             let species_id = o["specId"].as_str().ok_or("no specId")?;
+            if lenient && data.try_species_ref(species_id).is_none() {
+                object.diagnostics.push(SaveDiagnostic::UnknownSpecies {
+                    uid: id,
+                    species: species_id.to_string(),
+                    tank: o["hosting"]["host"].as_u64(),
+                });
+                return Ok(object);
+            }
             let species = data.species_ref(species_id)?;
-            let tank = read_hosting_tank(o, id)?;
 
             let animal = AnimalRef {
                 id: id,
                 species: species,
                 growth: read_growth(a, species)?,
             };
-
-            let vec = animals.entry(tank).or_insert(Vec::new());
-            vec.push(animal);
+            object.animal = Some((o["hosting"]["host"].as_u64(), animal, read_animal_state(a)?));
         }
 
         if obj.contains_key("aquascaping") || obj.contains_key("cornerMounted") {
-            let id = o["uid"].as_u64().ok_or("no id")?;
+            let id = o["uid"].as_u64().ok_or("no uid")?;
             let spec_id = o["specId"].as_str().ok_or("no specId")?;
 
-            // we don't model everything the game considers fixtures, so skip ones we don't know about
+            // anything we don't have a model for is reported rather than silently ignored
             if let Some(model) = data.try_fixture_ref(spec_id) {
                 let tank = read_hosting_tank(o, id)?;
-                let fixture = FixtureRef { id, model };
-
-                let vec = fixtures.entry(tank).or_insert(Vec::new());
-                vec.push(fixture);
+                object.fixture = Some((tank, FixtureRef { id, model }));
+            } else {
+                let reason = match data.skipped_fixtures.iter().find(|f| f.id == spec_id) {
+                    Some(skipped) => skipped.reason.clone(),
+                    None => "not in the loaded game data (is its DLC or mod enabled?)".to_string(),
+                };
+
+                object.diagnostics.push(SaveDiagnostic::UnknownFixture(UnmodeledFixture {
+                    uid: id,
+                    spec_id: spec_id.to_string(),
+                    tank: o["hosting"]["host"].as_u64(),
+                    reason,
+                }));
             }
         }
 
+        if let Some(model) = o["specId"].as_str().and_then(|s| data.try_equipment_ref(s)) {
+            let id = o["uid"].as_u64().ok_or("no uid")?;
+
+            // equipment that isn't serving a tank yet doesn't matter to any exhibit
+            object.equipment = read_equipment_tank(o).map(|tank| (tank, EquipmentRef { id, model }));
+        }
+
         if obj.contains_key("tank") {
-            let id = o["uid"].as_u64().ok_or("no specId")?;
+            let id = o["uid"].as_u64().ok_or("no uid")?;
             // this string contains both the model and the size in one munged string
             // they look like "<tank-type-id>_<x-dim>-<y-dim>" (e.g., lagoon_tank_3_4)
             let spec_id = o["specId"].as_str().ok_or(bad_json("no specId"))?;
 
             let name = o["name"].as_str().ok_or(bad_json("no name"))?;
 
-            let model = *models
-                .iter()
-                .find(|t| spec_id.starts_with(&t.id))
-                .ok_or(bad_json("No tank model"))?;
+            let model = match models.iter().find(|t| spec_id.starts_with(&t.id)) {
+                Some(model) => *model,
+                None if lenient => {
+                    object.diagnostics.push(SaveDiagnostic::UnknownTankModel {
+                        uid: id,
+                        spec_id: spec_id.to_string(),
+                    });
+                    return Ok(object);
+                }
+                None => return Err(Box::new(bad_json("No tank model"))),
+            };
             let size = {
                 // strip off the prefix and then split on '_' to get the dimensions
                 let string = &spec_id[model.id.len() + 1..];
@@ -190,32 +609,175 @@ pub fn read_save<'a>(data: &'a GameData, save_name: &str) -> Result<AquariumRef<
                 size: size,
             };
 
-            tanks.push((name.to_string(), tank));
+            object.tank = Some((name.to_string(), tank, read_location(o)?));
+        }
+
+        Ok(object)
+    };
+
+    for (i, o) in objects.iter().enumerate() {
+        match read_object(o) {
+            Ok(object) => {
+                uids.extend(object.uid);
+                park.staff.extend(object.staff);
+                if object.guest {
+                    park.guests += 1;
+                }
+                if let Some((host, animal, state)) = object.animal {
+                    animal_states.insert(animal.id, state);
+                    animals.push((host, animal));
+                }
+                if let Some((tank, fixture)) = object.fixture {
+                    fixtures.entry(tank).or_default().push(fixture);
+                }
+                if let Some((tank, e)) = object.equipment {
+                    equipment.entry(tank).or_default().push(e);
+                }
+                tanks.extend(object.tank);
+                diagnostics.extend(object.diagnostics);
+            }
+            Err(e) if lenient => diagnostics.push(SaveDiagnostic::MalformedObject {
+                index: i,
+                uid: o["uid"].as_u64(),
+                tank: o["hosting"]["host"].as_u64(),
+                error: e.to_string(),
+            }),
+            Err(e) => return in_object(&file, i, o, Err(e)),
+        }
+    }
+    let dropped: HashSet<u64> = diagnostics.iter().filter_map(|d| d.dropped_uid()).collect();
+
+    // what hosts an animal is only known once every object has been read
+    let tank_ids: HashSet<u64> = tanks.iter().map(|(_, t, _)| t.id).collect();
+    let staff: HashSet<u64> = park.staff.iter().map(|s| s.id).collect();
+    let hosting_of = |host: Option<u64>| match host {
+        Some(h) if tank_ids.contains(&h) => Hosting::Exhibit(h),
+        Some(h) if staff.contains(&h) => Hosting::InTransit(h),
+        Some(h) if uids.contains(&h) && !dropped.contains(&h) => Hosting::Holding(h),
+        _ => Hosting::Unknown,
+    };
+
+    let mut exhibit_animals: HashMap<u64, Vec<AnimalRef<'a>>> = HashMap::new();
+    let mut non_exhibit_animals: Vec<(AnimalRef<'a>, Hosting)> = Vec::new();
+    for (host, animal) in animals {
+        match hosting_of(host) {
+            Hosting::Exhibit(tank) => exhibit_animals.entry(tank).or_default().push(animal),
+            hosting => non_exhibit_animals.push((animal, hosting)),
         }
     }
 
-    let exhibits = tanks
-        .into_iter()
-        .map(|(name, tank)| {
-            let animals = match animals.remove(&tank.id) {
-                Some(list) => list,
-                None => Vec::new(),
-            };
-            let fixtures = match fixtures.remove(&tank.id) {
-                Some(list) => list,
-                None => Vec::new(),
-            };
+    let spoiled: HashSet<u64> = diagnostics.iter().filter_map(|d| d.spoiled_tank()).collect();
+    let mut exhibits = Vec::new();
+    for (name, tank, location) in tanks {
+        if spoiled.contains(&tank.id) {
+            diagnostics.push(SaveDiagnostic::IncompleteExhibit { tank: tank.id, name });
+            continue;
+        }
 
-            ExhibitRef {
-                name,
-                tank,
-                animals,
-                fixtures,
+        let animals = exhibit_animals.remove(&tank.id).unwrap_or_default();
+        let fixtures = fixtures.remove(&tank.id).unwrap_or_default();
+        let equipment = equipment.remove(&tank.id).unwrap_or_default();
+
+        exhibits.push(ExhibitRef {
+            name,
+            tank,
+            animals,
+            fixtures,
+            equipment,
+            location,
+        });
+    }
+
+    Ok(Save {
+        aquarium: AquariumRef {
+            exhibits,
+            numbered_animals: Default::default(),
+        },
+        park,
+        animal_states,
+        non_exhibit_animals,
+        diagnostics,
+    })
+}
+
+/// Where a tank is: `position` has its tile and floor, and `zone` names the zone it's in. Older
+/// saves and single-floor parks leave these out.
+fn read_location(o: &Value) -> Result<Location> {
+    let position = &o["position"];
+    let coordinates = match (&position["x"], &position["y"]) {
+        (Value::Null, _) | (_, Value::Null) => None,
+        (x, y) => {
+            let coordinate = |v: &Value| -> Result<i32> { Ok(v.as_i64().ok_or(bad_json("position is not a number"))?.try_into()?) };
+            Some((coordinate(x)?, coordinate(y)?))
+        }
+    };
+    let zone = match &o["zone"] {
+        Value::Null => None,
+        z => Some(z.as_str().ok_or(bad_json("zone is not a string"))?.to_string()),
+    };
+
+    Ok(Location {
+        floor: uint_or_none(&position["floor"])?,
+        zone,
+        position: coordinates,
+    })
+}
+
+/// The park's finances live next to the objects, in `park`. Staff and guests are filled in from the
+/// objects.
+fn read_park(json: &Value, file: &str) -> Result<ParkState> {
+    let park = &json["park"];
+    let money = match &park["money"] {
+        Value::Null => None,
+        v => Some(v.as_i64().ok_or_else(|| file_error(file, "money is not a number"))?),
+    };
+
+    Ok(ParkState {
+        money,
+        prestige: uint_or_none(&park["prestige"]).map_err(|e| file_error(file, e.to_string()))?,
+        ..ParkState::default()
+    })
+}
+
+fn read_staff_member(o: &Value, staff: &Value) -> Result<StaffMember> {
+    let skills = &staff["skills"];
+
+    Ok(StaffMember {
+        id: o["uid"].as_u64().ok_or("no uid")?,
+        name: o["name"].as_str().map(|n| n.to_string()),
+        role: o["specId"].as_str().ok_or("no specId")?.to_string(),
+        feeding: uint_or_default(&skills["feeding"], 0)?,
+        maintenance: uint_or_default(&skills["maintenance"], 0)?,
+    })
+}
+
+/// Equipment inside the tank (lights, skimmers) is hosted like a fixture, equipment standing next
+/// to it records which tank it's plumbed into.
+fn read_equipment_tank(o: &Value) -> Option<u64> {
+    o["hosting"]["host"].as_u64().or_else(|| o["lifeSupport"]["tank"].as_u64())
+}
+
+/// Health and happiness are fractions in the save, we keep them as percentages.
+fn read_animal_state(v: &Value) -> Result<AnimalState> {
+    let percent = |key: &str| -> Result<Option<u8>> {
+        match &v[key] {
+            Value::Null => Ok(None),
+            x => {
+                let fraction = x.as_f64().ok_or(bad_json(format!("{} is not a number", key)))?;
+                Ok(Some((fraction.clamp(0.0, 1.0) * 100.0).round() as u8))
             }
-        })
-        .collect();
+        }
+    };
+    let unmet_needs = match &v["unmetNeeds"] {
+        Value::Null => Vec::new(),
+        x => as_string_array(x)?.into_iter().map(|n| n.to_string()).collect(),
+    };
 
-    Ok(AquariumRef { exhibits: exhibits })
+    Ok(AnimalState {
+        health: percent("health")?,
+        happiness: percent("happiness")?,
+        unmet_needs,
+    })
 }
 
 fn read_growth(v: &Value, s: &Species) -> Result<Growth> {
@@ -237,49 +799,98 @@ fn read_growth(v: &Value, s: &Species) -> Result<Growth> {
     }
 }
 
+/// A failure reading a game data or save file, pointing at the object that caused it, e.g.
+/// "Data/animals.data: object 'clown_fish': unknown prey type".
 #[derive(Debug)]
-struct WrappedError {
-    pub message: String,
+pub struct DataError {
+    pub file: String,
+    /// position in the file's list of objects, None if the file as a whole is broken
+    pub index: Option<usize>,
+    /// the object's `id`, or `uid` for objects in a save
+    pub id: Option<String>,
     pub inner: Box<dyn Error>,
 }
 
-impl fmt::Display for WrappedError {
+impl fmt::Display for DataError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}: {}", self.message, self.inner)
+        match (&self.id, self.index) {
+            (Some(id), _) => write!(f, "{}: object '{}': {}", self.file, id, self.inner),
+            (None, Some(i)) => write!(f, "{}: object #{}: {}", self.file, i, self.inner),
+            (None, None) => write!(f, "{}: {}", self.file, self.inner),
+        }
     }
 }
 
-impl Error for WrappedError {}
+impl Error for DataError {}
+
+fn file_error<S: Into<String>>(file: &str, msg: S) -> Box<dyn Error> {
+    Box::new(DataError {
+        file: file.to_string(),
+        index: None,
+        id: None,
+        inner: error(msg),
+    })
+}
+
+/// Attaches the file and object to an error from reading a single object.
+fn in_object<T>(file: &str, index: usize, o: &Value, result: Result<T>) -> Result<T> {
+    result.map_err(|inner| {
+        let id = match (o["id"].as_str(), o["uid"].as_u64()) {
+            (Some(id), _) => Some(id.to_string()),
+            (None, Some(uid)) => Some(uid.to_string()),
+            (None, None) => None,
+        };
+
+        Box::new(DataError {
+            file: file.to_string(),
+            index: Some(index),
+            id,
+            inner,
+        }) as Box<dyn Error>
+    })
+}
+
+fn objects<'a>(json: &'a Value, file: &str) -> Result<&'a Vec<Value>> {
+    json["objects"].as_array().ok_or_else(|| file_error(file, "no objects"))
+}
+
+/// Reads every object in a game data file that `read` recognizes.
+fn read_objects<T>(directory: &Path, file: &str, read: fn(&Value) -> Result<Option<T>>) -> Result<Vec<T>> {
+    let json = read_json(directory, file)?;
+    let mut result = Vec::new();
+
+    for (i, o) in objects(&json, file)?.iter().enumerate() {
+        if let Some(x) = in_object(file, i, o, read(o))? {
+            result.push(x);
+        }
+    }
+
+    Ok(result)
+}
 
 #[derive(Debug, Clone)]
 struct BadJson {
-    pub message: Option<String>,
+    pub message: String,
 }
 
-const UBJ: BadJson = BadJson { message: None };
-
 fn bad_json<S: Into<String>>(msg: S) -> BadJson {
-    BadJson { message: Some(msg.into()) }
+    BadJson { message: msg.into() }
 }
 
 impl fmt::Display for BadJson {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let msg = match &self.message {
-            Some(s) => &s,
-            None => "Bad json for game data",
-        };
-        write!(f, "{}", msg)
+        write!(f, "{}", self.message)
     }
 }
 
 impl Error for BadJson {}
 
 fn as_string_array(json: &Value) -> Result<Vec<&str>> {
-    let jarr = json.as_array().ok_or(UBJ)?;
+    let jarr = json.as_array().ok_or(bad_json("expected a list"))?;
     let sarr: Result<Vec<&str>> = jarr
         .iter()
         .map(|t| {
-            let s = t.as_str().ok_or(UBJ)?;
+            let s = t.as_str().ok_or(bad_json("expected a list of strings"))?;
             Ok(s)
         })
         .collect();
@@ -320,10 +931,23 @@ fn optional_u8(obj: Option<&Value>, key: &str) -> Result<Option<u8>> {
     }
 }
 
+/// Prices, upkeep and prestige live in the same `economy` object on animals, tanks and scenery.
+fn read_economy(o: &Value) -> Result<Economy> {
+    let economy = &o["economy"];
+
+    Ok(Economy {
+        cost: uint_or_none(&economy["cost"])?,
+        upkeep: uint_or_none(&economy["upkeep"])?,
+        prestige: uint_or_none(&economy["prestige"])?,
+    })
+}
+
 fn stat_number(stats: &Map<String, Value>, stat: &str, key: &str) -> Result<Option<u8>> {
     match stats.get(stat) {
         None => Ok(None),
-        Some(v) => Ok(Some(v[key].as_u64().ok_or(UBJ)?.try_into()?)),
+        Some(v) => Ok(Some(
+            v[key].as_u64().ok_or(bad_json(format!("{} has no {}", stat, key)))?.try_into()?,
+        )),
     }
 }
 
@@ -338,41 +962,105 @@ fn maybe_stat_value(stats: Option<&Map<String, Value>>, stat: &str) -> Result<Op
     }
 }
 
-fn read_species(directory: &Path) -> Result<Vec<Species>> {
-    let mut animals = Vec::new();
+// The keys the loaders below understand, for `audit-data` to report anything new a patch adds.
+// These need to be kept in sync with read_single_species, read_single_tank_model and
+// read_single_fixture_model.
+pub const KNOWN_SPECIES_STATS: &[&str] = &[
+    "activeSwimmer",
+    "armored",
+    "baby",
+    "breeder",
+    "bully",
+    "communal",
+    "congenersOnly",
+    "dislikesCongeners",
+    "dislikesConspecifics",
+    "dislikesFoodCompetitors",
+    "dislikesLights",
+    "eater",
+    "eats",
+    "explorer",
+    "greedy",
+    "isAnemone",
+    "isClam",
+    "isColdwater",
+    "isCrustacean",
+    "isFish",
+    "isGastropod",
+    "isGorgonian",
+    "isSeaSquirt",
+    "isSoftCoral",
+    "isStarfish",
+    "isStonyCoral",
+    "isTropical",
+    "isTubeWorm",
+    "light",
+    "likesBogwood",
+    "likesCave",
+    "likesFlatSurface",
+    "likesFluffyFoliage",
+    "likesPlants",
+    "likesRocks",
+    "likesVerticalSurface",
+    "needsFeedingSkill",
+    "needsKreisel",
+    "needsRounded",
+    "nibbleable",
+    "nibbler",
+    "openSpace",
+    "pairsOnly",
+    "scavenger",
+    "shoaler",
+    "territorial",
+    "waterQuality",
+    "wimp",
+];
+pub const KNOWN_TANK_KEYS: &[&str] = &["isKreisel", "isRounded", "volumePerTile"];
+pub const KNOWN_AQUASCAPING_STATS: &[&str] = &[
+    "isBogwood",
+    "isCave",
+    "isFlatSurface",
+    "isFluffyFoliage",
+    "isPlant",
+    "isRock",
+    "isVerticalSurface",
+];
+pub const KNOWN_CHEMISTRY_KEYS: &[&str] = &["light"];
+
+/// Every object in the animal, tank and fixture files of the game, its installed DLC and the given
+/// mods, without interpreting them.
+pub fn read_raw_objects(directory: &Path, mods: &[PathBuf]) -> Result<Vec<Value>> {
+    let mut result = Vec::new();
 
-    for path in FISH_PATHS {
-        let list = read_species_file(&read_json(directory, path)?)?;
-        animals.extend(list);
+    let sources = std::iter::once(&BASE_GAME_FILES).chain(installed_dlc(directory).into_iter().map(dlc_files));
+    for files in sources {
+        for file in files.animals.iter().chain(files.tanks).chain(files.fixtures) {
+            let json = read_json(directory, file)?;
+            result.extend(objects(&json, file)?.iter().cloned());
+        }
     }
 
-    Ok(animals)
-}
-
-fn read_species_file(json: &Value) -> Result<Vec<Species>> {
-    let objects = json["objects"].as_array().ok_or("no species objects")?;
-    let mut result = Vec::with_capacity(objects.len());
-    for o in objects {
-        match read_single_species_wrapper(o)? {
-            Some(s) => result.push(s),
-            None => (),
-        };
+    for m in mods {
+        let root = m.parent().unwrap_or(m);
+        for path in mod_data_files(m) {
+            let file = path.strip_prefix(root).unwrap_or(&path).to_string_lossy().to_string();
+            let json = read_json(root, &file)?;
+            result.extend(objects(&json, &file)?.iter().cloned());
+        }
     }
+
     Ok(result)
 }
 
-fn read_single_species_wrapper(o: &Value) -> Result<Option<Species>> {
-    match read_single_species(o) {
-        Ok(r) => Ok(r),
-        Err(e) => {
-            let id = o["id"].as_str().unwrap_or("unknown");
-            let wrapped = WrappedError {
-                message: format!("error reading species {}", id),
-                inner: e,
-            };
-            Err(Box::new(wrapped))
-        }
+fn read_species(directory: &Path, paths: &[&str], dlc: Option<Dlc>) -> Result<Vec<Species>> {
+    let mut animals = Vec::new();
+
+    for path in paths {
+        let list = read_objects(directory, path, read_single_species)?;
+        animals.extend(list.into_iter().map(|s| Species { dlc, ..s }));
     }
+
+    Ok(animals)
 }
 
 fn read_single_species(o: &Value) -> Result<Option<Species>> {
@@ -415,28 +1103,28 @@ fn read_single_species(o: &Value) -> Result<Option<Species>> {
         Ok(result)
     }
 
-    let size = {
-        let raw_stages = animal["stages"].as_array().ok_or(UBJ)?;
-        let stages = raw_stages
-            .iter()
-            .map(|s| {
-                let size = uint_or_default(&s["size"], 0)?;
-                let time = uint_or_none(&s["growthTime"])?;
-                Ok((size, time))
-            })
-            .collect::<Result<Vec<(u16, Option<u16>)>>>()?;
+    let raw_stages = animal["stages"].as_array().ok_or(bad_json("no stages"))?;
+    let stages = raw_stages
+        .iter()
+        .map(|s| {
+            let size = uint_or_default(&s["size"], 0)?;
+            let time = uint_or_none(&s["growthTime"])?;
+            Ok((size, time))
+        })
+        .collect::<Result<Vec<(u16, Option<u16>)>>>()?;
 
+    let size = {
         let mut last_duration = 0;
 
         Size {
-            final_size: stages.last().ok_or(UBJ)?.0,
+            final_size: stages.last().ok_or(bad_json("no stages"))?.0,
             armored: has_stat(stats, "armored"),
             immobile: o.as_object().unwrap().contains_key("immobile"),
             stages: stages
                 .iter()
                 .take(stages.len() - 1)
                 .map(|(sz, d)| {
-                    let duration = d.ok_or(UBJ)?;
+                    let duration = d.ok_or(bad_json("no growthTime for a stage before the last"))?;
                     let result = Stage {
                         size: *sz,
                         duration: duration - last_duration,
@@ -493,7 +1181,7 @@ fn read_single_species(o: &Value) -> Result<Option<Species>> {
 
     let diet = {
         if let Some(e) = &stats.get("eats") {
-            let food = e["item"].as_str().ok_or(UBJ)?.to_string();
+            let food = e["item"].as_str().ok_or(bad_json("eats has no item"))?.to_string();
             let period = uint_or_default(&e["daysBetweenFeed"], 0)? + 1;
             let skill = stat_value(stats, "needsFeedingSkill")?.unwrap_or(0);
 
@@ -615,12 +1303,33 @@ fn read_single_species(o: &Value) -> Result<Option<Species>> {
                 .and_then(|b| b.as_str())
                 .ok_or(bad_json("no babySpec"))?
                 .to_string();
-            Breeding::Breedable(Breedable { baby })
+
+            let mut conditions = Vec::new();
+            if has_true_value(breeder, "needsPartner") {
+                conditions.push(BreedingCondition::Partner);
+            }
+            if let Some(n) = uint_or_none(&stats["breeder"]["minGroup"])? {
+                conditions.push(BreedingCondition::Group(n));
+            }
+            if let Some(h) = uint_or_none(&stats["breeder"]["minHappiness"])? {
+                conditions.push(BreedingCondition::Happiness(h));
+            }
+
+            Breeding::Breedable(Breedable {
+                baby,
+                interval: uint_or_none(&stats["breeder"]["interval"])?,
+                conditions,
+            })
         } else {
             Breeding::CannotBread
         }
     } else {
-        Breeding::NotFullyGrown
+        // growthTime is cumulative, so the time in the final stage is what's left after the others
+        let grown_by: u16 = size.stages.iter().map(|s| s.duration).sum();
+        Breeding::NotFullyGrown(Young {
+            grows_into: animal.get("growsInto").and_then(|g| g.as_str()).map(|g| g.to_string()),
+            duration: stages.last().and_then(|(_, t)| *t).map(|t| t.saturating_sub(grown_by)),
+        })
     };
 
     Ok(Some(Species {
@@ -639,33 +1348,63 @@ fn read_single_species(o: &Value) -> Result<Option<Species>> {
         predation,
         communal: stat_value(stats, "communal")?,
         breeding,
+        economy: read_economy(o)?,
+        names: Names::default(),
+        unlock_level: uint_or_none(&o["research"]["level"])?,
+        dlc: None,
+        mod_name: None,
     }))
 }
 
-fn read_fixture_models(directory: &Path) -> Result<Vec<FixtureModel>> {
+fn read_fixture_models(directory: &Path, paths: &[&str], dlc: Option<Dlc>) -> Result<Vec<FixtureModel>> {
     let mut fixtures = Vec::new();
 
-    for path in FIXTURE_PATHS {
-        let json = read_json(directory, path)?;
-        let objects = json["objects"].as_array().ok_or("no tank objects")?;
-        for x in objects {
-            match read_single_fixture_model(x)? {
-                Some(fixture) => fixtures.push(fixture),
-                None => (),
-            };
-        }
+    for path in paths {
+        let list = read_objects(directory, path, read_single_fixture_model)?;
+        fixtures.extend(list.into_iter().map(|f| FixtureModel { dlc, ..f }));
     }
 
     Ok(fixtures)
 }
 
+fn read_skipped_fixtures(directory: &Path, paths: &[&str]) -> Result<Vec<SkippedFixture>> {
+    let mut skipped = Vec::new();
+
+    for path in paths {
+        skipped.extend(read_objects(directory, path, |o| {
+            Ok(fixture_skip_reason(o)?.map(|reason| SkippedFixture {
+                id: o["id"].as_str().unwrap_or("unknown").to_string(),
+                reason: reason.to_string(),
+            }))
+        })?);
+    }
+
+    Ok(skipped)
+}
+
+/// Anything that goes in a tank as decoration is a fixture: whatever has aquascaping stats or is
+/// mounted in a corner (lights), plus the scenery and lights that are tagged but have neither.
+fn fixture_skip_reason(o: &Value) -> Result<Option<&'static str>> {
+    let obj = o.as_object().ok_or(bad_json("object is not json object"))?;
+    if obj.contains_key("aquascaping") || obj.contains_key("cornerMounted") {
+        return Ok(None);
+    }
+
+    let tags = as_string_array(&o["tags"])?;
+    if tags.iter().any(|t| *t == "scenery" || *t == "light") {
+        Ok(None)
+    } else if obj.contains_key("lifeSupport") || tags.contains(&"equipment") {
+        Ok(Some("life support equipment, not a decoration"))
+    } else {
+        Ok(Some("neither aquascaping nor corner mounted"))
+    }
+}
+
 fn read_single_fixture_model(o: &Value) -> Result<Option<FixtureModel>> {
     let obj = o.as_object().unwrap();
     let id = obj["id"].as_str().ok_or("no id")?;
 
-    // Filter to only include objects with "scenery" or "light" tags
-    let tags = as_string_array(&o["tags"])?;
-    if !tags.iter().any(|t| *t == "scenery" || *t == "light") {
+    if fixture_skip_reason(o)?.is_some() {
         return Ok(None);
     }
 
@@ -695,21 +1434,64 @@ fn read_single_fixture_model(o: &Value) -> Result<Option<FixtureModel>> {
         flat_surfaces,
         vertical_surfaces,
         fluffy_foliage,
+        economy: read_economy(o)?,
+        names: Names::default(),
+        unlock_level: uint_or_none(&o["research"]["level"])?,
+        dlc: None,
+        mod_name: None,
     }))
 }
 
-fn read_tank_models(directory: &Path) -> Result<Vec<TankModel>> {
-    let mut tanks = Vec::new();
+fn read_equipment_models(directory: &Path, paths: &[&str], dlc: Option<Dlc>) -> Result<Vec<EquipmentModel>> {
+    let mut equipment = Vec::new();
+
+    for path in paths {
+        let list = read_objects(directory, path, read_single_equipment_model)?;
+        equipment.extend(list.into_iter().map(|e| EquipmentModel { dlc, ..e }));
+    }
 
-    for path in TANK_PATHS {
-        let json = read_json(directory, path)?;
-        let objects = json["objects"].as_array().ok_or("no tank objects")?;
-        for x in objects {
-            let tank = read_single_tank_model(x)?;
-            tanks.push(tank);
+    Ok(equipment)
+}
+
+fn read_single_equipment_model(o: &Value) -> Result<Option<EquipmentModel>> {
+    let obj = o.as_object().ok_or(bad_json("object is not json object"))?;
+    let id = obj["id"].as_str().ok_or("no id")?;
+
+    let tags = as_string_array(&o["tags"])?;
+    let life_support = obj.get("lifeSupport").and_then(|l| l.as_object());
+
+    if !(life_support.is_some() || tags.iter().any(|t| *t == "equipment" || *t == "light")) {
+        return Ok(None);
+    }
+
+    fn capacity(stats: Option<&Map<String, Value>>, stat: &str) -> Result<Option<u16>> {
+        match stats.and_then(|s| s.get(stat)) {
+            None => Ok(None),
+            Some(v) => uint_or_none(&v["value"]),
         }
     }
 
+    Ok(Some(EquipmentModel {
+        id: id.to_string(),
+        filtration: capacity(life_support, "filtration")?,
+        heating: capacity(life_support, "heating")?,
+        cooling: capacity(life_support, "cooling")?,
+        skimming: capacity(life_support, "proteinSkimming")?,
+        lighting: optional_u8(obj.get("chemistry"), "light")?,
+        names: Names::default(),
+        dlc: None,
+        mod_name: None,
+    }))
+}
+
+fn read_tank_models(directory: &Path, paths: &[&str], dlc: Option<Dlc>) -> Result<Vec<TankModel>> {
+    let mut tanks = Vec::new();
+
+    for path in paths {
+        let list = read_objects(directory, path, |o| read_single_tank_model(o).map(Some))?;
+        tanks.extend(list.into_iter().map(|t| TankModel { dlc, ..t }));
+    }
+
     Ok(tanks)
 }
 
@@ -728,7 +1510,7 @@ fn read_single_tank_model(o: &Value) -> Result<TankModel> {
         Ok((w, h))
     };
 
-    let density = tank["volumePerTile"].as_f64().ok_or(UBJ)?;
+    let density = tank["volumePerTile"].as_f64().ok_or(bad_json("no volumePerTile"))?;
 
     let interior = if bool_or_default(&tank["isRounded"], false) {
         Some(Interior::Rounded)
@@ -744,62 +1526,77 @@ fn read_single_tank_model(o: &Value) -> Result<TankModel> {
         max_size: read_size("baseSize")?,
         double_density: (2.0 * density).round() as u16,
         interior,
+        economy: read_economy(o)?,
+        names: Names::default(),
+        unlock_level: uint_or_none(&o["research"]["level"])?,
+        dlc: None,
+        mod_name: None,
     })
 }
 
-fn read_food(directory: &Path) -> Result<Vec<String>> {
+fn read_food(directory: &Path, paths: &[&str], dlc: Option<Dlc>) -> Result<Vec<FoodModel>> {
     let mut food = Vec::new();
 
-    for path in FOOD_PATHS {
-        let json = read_json(directory, path)?;
-        let objects = json["objects"].as_array().ok_or("no tank objects")?;
-        for x in objects {
-            let value = read_single_food(x)?;
-            match value {
-                Some(v) => food.push(v),
-                None => (),
-            };
-        }
+    for path in paths {
+        let list = read_objects(directory, path, read_single_food)?;
+        food.extend(list.into_iter().map(|f| FoodModel { dlc, ..f }));
     }
 
     Ok(food)
 }
 
-fn read_single_food(o: &Value) -> Result<Option<String>> {
+fn read_single_food(o: &Value) -> Result<Option<FoodModel>> {
     let id = o["id"].as_str().ok_or("no id")?;
     let tags = as_string_array(&o["tags"])?;
 
-    if tags.iter().any(|t| *t == "animalFood") {
-        Ok(Some(id.to_string()))
-    } else {
-        Ok(None)
+    if !tags.contains(&"animalFood") {
+        return Ok(None);
     }
+
+    // not every food has these, so they're all optional
+    let food = &o["animalFood"];
+
+    Ok(Some(FoodModel {
+        id: id.to_string(),
+        cost: uint_or_none(&food["cost"])?,
+        storage: food["storage"].as_str().map(|s| s.to_string()),
+        preparation: food["preparedAt"].as_str().map(|s| s.to_string()),
+        names: Names::default(),
+        dlc: None,
+        mod_name: None,
+    }))
 }
 
-fn read_json(directory: &Path, file: &str) -> Result<Value> {
-    // serde's parser is strict (the maintainers have "never seen json with comments" (lol)),
-    // so we do some gross regex stuff to purge trailing commas and comments, since that's
-    // easier than writing an entire parser or using some unmaintained library.
-    lazy_static! {
-        static ref REGEXES: [(Regex,&'static str); 5] = [
-            // comments
-            (Regex::new("//.*?\n").unwrap(), "\n"),
-            (Regex::new("(?s)/\\*.*?\\*/").unwrap(), ""),
-            // trailing commas (needs to be after comments)
-            (Regex::new(",([\r\n \t]*\\})").unwrap(), "$1"),
-            (Regex::new(",([\r\n \t]*\\])").unwrap(), "$1"),
-            // multiline strings in tanks.data and scenery.data
-            (Regex::new("(?s)\"map\":\\s*\".*?\"").unwrap(), "\"map\":\"\""),
-        ];
+/// Reads the display names from every string file, by entity id. A string file is a json object
+/// whose `strings` map ids to what the game shows for them in that file's language.
+fn read_names(directory: &Path) -> Result<HashMap<String, Names>> {
+    let mut result: HashMap<String, Names> = HashMap::new();
 
-    }
+    for (lang, file) in language_files(directory) {
+        let json = read_json(directory, &file)?;
+        let strings = json["strings"].as_object().ok_or_else(|| file_error(&file, "no strings"))?;
 
-    let mut file = fs::read_to_string(directory.join(file))?;
-    for (regex, replacement) in REGEXES.iter() {
-        file = regex.replace_all(&file, *replacement).to_string();
+        for (id, name) in strings {
+            let name = name
+                .as_str()
+                .ok_or_else(|| file_error(&file, format!("string {} is not a string", id)))?;
+            result.entry(id.clone()).or_default().0.insert(lang.clone(), name.to_string());
+        }
     }
 
-    let result = from_str(&file)?;
+    Ok(result)
+}
+
+fn read_json(directory: &Path, file: &str) -> Result<Value> {
+    // the game's files are lenient about comments, trailing commas and multiline strings, which
+    // serde's parser won't accept, so we have our own reader
+    let text = fs::read_to_string(directory.join(file)).map_err(|e| DataError {
+        file: file.to_string(),
+        index: None,
+        id: None,
+        inner: Box::new(e),
+    })?;
+    let result = json::from_str(&text).map_err(|e| e.in_file(file))?;
 
     Ok(result)
 }
@@ -808,19 +1605,17 @@ fn read_json(directory: &Path, file: &str) -> Result<Value> {
 mod test {
     use super::*;
     use crate::animal::test::*;
+    use crate::food::test::test_food;
+    use crate::util::test::TempDir;
+    use std::collections::BTreeMap;
 
-    fn test_data(species: Vec<Species>) -> GameData {
-        GameData {
-            species,
-            tanks: vec![],
-            fixtures: vec![],
-            food: vec![],
-        }
+    fn installed_game_data() -> GameData {
+        read_game_data(&find_data_dir(None).unwrap(), &[]).unwrap()
     }
 
     #[test]
     fn test_try_species_ref() {
-        let data = test_data(vec![test_species("foo"), test_species("bar")]);
+        let data = GameData::with_species(vec![test_species("foo"), test_species("bar")]);
         let foo = &data.species[0];
         let bar = &data.species[1];
 
@@ -832,7 +1627,7 @@ mod test {
 
     #[test]
     fn test_species_search() {
-        let data = test_data(vec![
+        let data = GameData::with_species(vec![
             test_species("2_crescent_earthen"),
             test_species("4_pancake_scuppernong"),
             test_species("7_violet_crescent"),
@@ -851,16 +1646,548 @@ mod test {
         assert_eq!(data.species_search("e"), vec![two, four, seven]);
     }
 
+    #[test]
+    fn test_species_search_by_name() {
+        let mut data = GameData::with_species(vec![test_species("33_arabian_butterflyfish"), test_species("1_clown_fish")]);
+        data.food.push(test_food("krill"));
+        let names = HashMap::from([
+            (
+                "1_clown_fish".to_string(),
+                Names(BTreeMap::from([("en".to_string(), "Clownfish".to_string())])),
+            ),
+            (
+                "krill".to_string(),
+                Names(BTreeMap::from([("de".to_string(), "Krill".to_string())])),
+            ),
+        ]);
+        data.apply_names(&names);
+
+        assert_eq!(data.species_search("arabian butterflyfish"), vec![&data.species[0]]);
+        assert_eq!(data.species_search("clownfish"), vec![&data.species[1]]);
+        assert_eq!(data.species[1].names.get("en"), Some("Clownfish"));
+        assert_eq!(data.species[0].names, Names::default());
+        assert_eq!(data.food[0].names.get("de"), Some("Krill"));
+        assert_eq!(data.languages(), vec!["de", "en"]);
+    }
+
+    #[test]
+    fn test_restrict_to_unlocked() {
+        let locked = |id: &str, level| Species {
+            unlock_level: Some(level),
+            ..test_species(id)
+        };
+        let data = GameData::with_species(vec![
+            test_species("starter"),
+            locked("tang", 2),
+            locked("shark", 4),
+            locked("eel", 4),
+        ]);
+        let progress = Progress {
+            level: 2,
+            unlocked: vec!["eel".to_string()],
+        };
+
+        let data = data.restrict_to_unlocked(&progress);
+
+        let ids: Vec<&str> = data.species.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["starter", "tang", "eel"]);
+        assert_eq!(data.species_search("shark"), Vec::<&Species>::new());
+    }
+
+    #[test]
+    fn test_known_species_stats_are_all_read() {
+        // between them these use every stat, each species only one of those that exclude each other
+        let v = serde_json::json!({ "value": 2 });
+        let variants = [
+            serde_json::json!({
+                "isTropical": {}, "isFish": {}, "activeSwimmer": {}, "armored": {}, "territorial": {},
+                "needsRounded": {}, "waterQuality": { "value": 70 },
+                "eats": { "item": "flakes", "daysBetweenFeed": 1 }, "needsFeedingSkill": v,
+                "likesPlants": v, "likesRocks": v, "likesCave": v, "likesBogwood": v, "likesFlatSurface": v,
+                "likesVerticalSurface": v, "likesFluffyFoliage": v, "openSpace": v, "explorer": v, "light": v,
+                "greedy": {}, "shoaler": { "req": 3 }, "dislikesConspecifics": {}, "wimp": {}, "nibbleable": {},
+                "eater": { "fishEater": {} }, "communal": v, "breeder": { "babySpec": "fish.fry" },
+            }),
+            serde_json::json!({
+                "isColdwater": {}, "isStarfish": {}, "needsKreisel": {}, "scavenger": {}, "dislikesLights": {},
+                "dislikesCongeners": {}, "bully": {}, "nibbler": {},
+            }),
+            serde_json::json!({ "isTropical": {}, "isCrustacean": {}, "congenersOnly": {} }),
+            serde_json::json!({ "isTropical": {}, "isStonyCoral": {}, "dislikesFoodCompetitors": {} }),
+            serde_json::json!({ "isTropical": {}, "isSoftCoral": {}, "pairsOnly": {} }),
+            serde_json::json!({ "isTropical": {}, "baby": {} }),
+            serde_json::json!({ "isTropical": {}, "isClam": {} }),
+            serde_json::json!({ "isTropical": {}, "isGorgonian": {} }),
+            serde_json::json!({ "isTropical": {}, "isAnemone": {} }),
+            serde_json::json!({ "isTropical": {}, "isTubeWorm": {} }),
+            serde_json::json!({ "isTropical": {}, "isSeaSquirt": {} }),
+            serde_json::json!({ "isTropical": {}, "isGastropod": {} }),
+        ];
+        let animal = |stats: &Value| {
+            serde_json::json!({
+                "id": "fish",
+                "tags": ["animal", "fishes"],
+                "animal": { "stats": stats, "stages": [{ "size": 2 }] },
+            })
+        };
+        let objects: Vec<Value> = variants.iter().map(animal).collect();
+
+        let mut used: Vec<&str> = variants
+            .iter()
+            .flat_map(|s| s.as_object().unwrap().keys())
+            .map(|k| k.as_str())
+            .collect();
+        used.sort();
+        used.dedup();
+        assert_eq!(used, KNOWN_SPECIES_STATS);
+        assert_eq!(crate::audit::audit_objects(&objects), vec![]);
+
+        // and the loader reads every one of them: leaving any out changes the species
+        for stats in &variants {
+            let species = read_single_species(&animal(stats)).unwrap();
+            for key in stats.as_object().unwrap().keys() {
+                let mut without = stats.clone();
+                without.as_object_mut().unwrap().remove(key);
+                assert_ne!(
+                    read_single_species(&animal(&without)).ok().flatten(),
+                    species,
+                    "{} is not read",
+                    key
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_breeding_and_link_life_cycles() {
+        let animal = |id: &str, stats: Value, stages: Value| {
+            serde_json::json!({
+                "id": id,
+                "tags": ["animal", "amphiprion"],
+                "animal": { "stats": stats, "stages": stages },
+            })
+        };
+        let adult = animal(
+            "clown",
+            serde_json::json!({
+                "isTropical": {},
+                "isFish": {},
+                "breeder": { "babySpec": "clown.egg", "interval": 20, "needsPartner": true, "minGroup": 3 },
+            }),
+            serde_json::json!([{ "size": 2 }]),
+        );
+        let egg = animal(
+            "clown.egg",
+            serde_json::json!({ "isTropical": {}, "baby": {} }),
+            serde_json::json!([{ "size": 1, "growthTime": 2 }, { "size": 1, "growthTime": 5 }]),
+        );
+        let fry = animal(
+            "clown.fry",
+            serde_json::json!({ "isTropical": {}, "baby": {} }),
+            serde_json::json!([{ "size": 1 }]),
+        );
+
+        let mut species: Vec<Species> = [adult, egg, fry].iter().map(|o| read_single_species(o).unwrap().unwrap()).collect();
+        link_life_cycles(&mut species);
+
+        assert_eq!(
+            species[0].breeding,
+            Breeding::Breedable(Breedable {
+                baby: "clown.egg".to_string(),
+                interval: Some(20),
+                conditions: vec![BreedingCondition::Partner, BreedingCondition::Group(3)],
+            })
+        );
+        assert_eq!(
+            species[1].breeding,
+            Breeding::NotFullyGrown(Young {
+                grows_into: Some("clown.fry".to_string()),
+                duration: Some(3),
+            })
+        );
+        assert_eq!(
+            species[2].breeding,
+            Breeding::NotFullyGrown(Young {
+                grows_into: Some("clown".to_string()),
+                duration: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_apply_mod_overrides_by_id() {
+        let mut data = GameData::with_species(vec![test_species("foo"), test_species("bar")]);
+        data.species[0].dlc = Some(Dlc::FreshwaterFrenzy);
+
+        let modded = |id: &str| Species {
+            mod_name: Some("123".to_string()),
+            communal: Some(4),
+            ..test_species(id)
+        };
+        data.apply_mod(GameData::with_species(vec![modded("foo"), modded("baz")]));
+
+        let ids: Vec<&str> = data.species.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["foo", "bar", "baz"]);
+        assert_eq!(data.species[0].communal, Some(4));
+        assert_eq!(data.species[0].mod_name.as_deref(), Some("123"));
+        assert_eq!(data.species[0].dlc, Some(Dlc::FreshwaterFrenzy));
+        assert_eq!(data.species[1].mod_name, None);
+        assert_eq!(data.species[2].dlc, None);
+    }
+
+    #[test]
+    fn test_read_mod_object_classifies_by_content() {
+        let mut data = GameData::with_species(vec![]);
+        let tank = serde_json::json!({
+            "id": "mega_tank",
+            "tank": { "volumePerTile": 3 },
+            "multisize": { "minSize": { "m": 2, "n": 2 }, "baseSize": { "m": 8, "n": 8 } },
+        });
+        let food = serde_json::json!({ "id": "mega_flakes", "tags": ["animalFood"] });
+        let other = serde_json::json!({ "id": "bench", "tags": ["guestThing"] });
+
+        for o in [tank, food, other] {
+            read_mod_object(&o, "456", &mut data).unwrap();
+        }
+
+        assert_eq!(data.tanks.len(), 1);
+        assert_eq!(data.tanks[0].id, "mega_tank");
+        assert_eq!(data.tanks[0].mod_name.as_deref(), Some("456"));
+        assert_eq!(data.food.len(), 1);
+        assert_eq!(data.food[0].id, "mega_flakes");
+        assert!(data.fixtures.is_empty());
+    }
+
+    #[test]
+    fn test_read_single_food() {
+        let krill = serde_json::json!({
+            "id": "krill",
+            "tags": ["animalFood"],
+            "animalFood": { "cost": 4, "storage": "frozen", "preparedAt": "food_prep_table" },
+        });
+        let food = read_single_food(&krill).unwrap().unwrap();
+        assert_eq!(food.cost, Some(4));
+        assert_eq!(food.storage.as_deref(), Some("frozen"));
+        assert_eq!(food.preparation.as_deref(), Some("food_prep_table"));
+
+        let flakes = serde_json::json!({ "id": "flakes", "tags": ["animalFood"] });
+        assert_eq!(read_single_food(&flakes).unwrap(), Some(test_food("flakes")));
+
+        let station = serde_json::json!({ "id": "food_station", "tags": ["building"] });
+        assert_eq!(read_single_food(&station).unwrap(), None);
+    }
+
+    #[test]
+    fn test_errors_name_file_and_object() {
+        let temp = TempDir::new("data-error");
+        let dir = temp.path();
+        fs::create_dir_all(dir.join("Data")).unwrap();
+        fs::write(
+            dir.join("Data/animals.data"),
+            r#"{ "objects": [
+                { "id": "clown_fish", "tags": ["animal"], "animal": { "stats": { "isTropical": {} }, "stages": [{ "size": 1 }] } }
+            ] }"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("Data/park.sav"),
+            r#"{ "objects": [ {}, { "uid": 7, "inGameWorld": true, "animal": {} } ] }"#,
+        )
+        .unwrap();
+
+        let err = read_species(dir, &["Data/animals.data"], None).unwrap_err();
+        assert_eq!(err.to_string(), "Data/animals.data: object 'clown_fish': unknown prey type");
+
+        let data = GameData::with_species(vec![]);
+        let err = read_save(&data, &dir.join("Data/park.sav")).unwrap_err();
+        assert_eq!(err.to_string(), "park.sav: object '7': no specId");
+
+        let err = read_species(dir, &["Data/missing.data"], None).unwrap_err();
+        assert!(err.to_string().starts_with("Data/missing.data: "));
+
+        let unnamed = serde_json::json!({});
+        let err = in_object("Data/tanks.data", 3, &unnamed, Err::<(), _>(error("no id"))).unwrap_err();
+        assert_eq!(err.to_string(), "Data/tanks.data: object #3: no id");
+    }
+
+    #[test]
+    fn test_read_economy() {
+        let rock = serde_json::json!({
+            "id": "rock",
+            "tags": ["scenery"],
+            "economy": { "cost": 120, "prestige": 2 },
+        });
+        let model = read_single_fixture_model(&rock).unwrap().unwrap();
+        assert_eq!(
+            model.economy,
+            Economy {
+                cost: Some(120),
+                upkeep: None,
+                prestige: Some(2),
+            }
+        );
+
+        let bare = serde_json::json!({ "id": "bare" });
+        assert_eq!(read_economy(&bare).unwrap(), Economy::default());
+
+        let negative = serde_json::json!({ "economy": { "upkeep": -1 } });
+        assert!(read_economy(&negative).is_err());
+    }
+
+    #[test]
+    fn test_fixture_skip_reason() {
+        let cave = serde_json::json!({ "id": "cave", "tags": ["decoration"], "aquascaping": { "stats": { "isCave": { "value": 1 } } } });
+        let mounted = serde_json::json!({ "id": "spotlight", "cornerMounted": {} });
+        let rock = serde_json::json!({ "id": "rock", "tags": ["scenery"] });
+        let filter = serde_json::json!({ "id": "filter", "tags": ["equipment"], "lifeSupport": {} });
+        let bench = serde_json::json!({ "id": "bench", "tags": ["guestThing"] });
+
+        assert_eq!(fixture_skip_reason(&cave).unwrap(), None);
+        assert_eq!(read_single_fixture_model(&cave).unwrap().unwrap().id, "cave");
+        assert_eq!(fixture_skip_reason(&mounted).unwrap(), None);
+        assert_eq!(fixture_skip_reason(&rock).unwrap(), None);
+        assert_eq!(
+            fixture_skip_reason(&filter).unwrap(),
+            Some("life support equipment, not a decoration")
+        );
+        assert_eq!(fixture_skip_reason(&bench).unwrap(), Some("neither aquascaping nor corner mounted"));
+        assert!(read_single_fixture_model(&bench).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_read_save_reports_unmodeled_fixtures() {
+        let dir = TempDir::new("unmodeled");
+        let path = dir.save(
+            r#"{ "objects": [
+                { "uid": 1, "specId": "filter", "inGameWorld": true, "aquascaping": {}, "hosting": { "host": 9 } },
+                { "uid": 2, "specId": "new_cave", "inGameWorld": true, "aquascaping": {} }
+            ] }"#,
+        );
+
+        let mut data = GameData::with_species(vec![]);
+        data.skipped_fixtures.push(SkippedFixture {
+            id: "filter".to_string(),
+            reason: "life support equipment, not a decoration".to_string(),
+        });
+
+        let save = read_save(&data, &path).unwrap();
+        assert!(save.aquarium.exhibits.is_empty());
+        assert_eq!(
+            save.diagnostics,
+            vec![
+                SaveDiagnostic::UnknownFixture(UnmodeledFixture {
+                    uid: 1,
+                    spec_id: "filter".to_string(),
+                    tank: Some(9),
+                    reason: "life support equipment, not a decoration".to_string(),
+                }),
+                SaveDiagnostic::UnknownFixture(UnmodeledFixture {
+                    uid: 2,
+                    spec_id: "new_cave".to_string(),
+                    tank: None,
+                    reason: "not in the loaded game data (is its DLC or mod enabled?)".to_string(),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_save_non_exhibit_animals() {
+        let dir = TempDir::new("hosting");
+        let path = dir.save(
+            r#"{ "objects": [
+                { "uid": 1, "specId": "clown", "inGameWorld": true, "animal": {}, "hosting": { "host": 10 } },
+                { "uid": 2, "specId": "clown", "inGameWorld": true, "animal": {}, "hosting": { "host": 11 } },
+                { "uid": 3, "specId": "clown", "inGameWorld": true, "animal": {}, "hosting": { "host": 12 } },
+                { "uid": 4, "specId": "clown", "inGameWorld": true, "animal": {} },
+                { "uid": 5, "specId": "clown", "inGameWorld": true, "animal": {}, "hosting": { "host": 99 } },
+                { "uid": 10, "specId": "basic_tank_3_3", "name": "Reef", "inGameWorld": true, "tank": {} },
+                { "uid": 11, "specId": "holding_tank", "inGameWorld": true, "holding": {} },
+                { "uid": 12, "specId": "aquarist", "inGameWorld": true, "staff": {} }
+            ] }"#,
+        );
+
+        let mut data = GameData::with_species(vec![test_species("clown")]);
+        data.tanks.push(crate::tank::test::test_tank_model("basic_tank"));
+
+        let save = read_save(&data, &path).unwrap();
+        let elsewhere: Vec<_> = save.non_exhibit_animals.iter().map(|(a, h)| (a.id, *h)).collect();
+
+        assert_eq!(save.aquarium.exhibits[0].animals.len(), 1);
+        assert_eq!(save.aquarium.exhibits[0].animals[0].id, 1);
+        assert_eq!(
+            elsewhere,
+            vec![
+                (2, Hosting::Holding(11)),
+                (3, Hosting::InTransit(12)),
+                (4, Hosting::Unknown),
+                (5, Hosting::Unknown),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_save_lenient() {
+        let dir = TempDir::new("lenient");
+        let path = dir.save(
+            r#"{ "objects": [
+                { "uid": 10, "specId": "basic_tank_3_3", "name": "Reef", "inGameWorld": true, "tank": {} },
+                { "uid": 11, "specId": "basic_tank_2_2", "name": "Pond", "inGameWorld": true, "tank": {} },
+                { "uid": 12, "specId": "round_tank_2_2", "name": "Bowl", "inGameWorld": true, "tank": {} },
+                { "uid": 13, "specId": "basic_tank_3_3", "name": "Lagoon", "inGameWorld": true, "tank": {} },
+                { "uid": 1, "specId": "clown", "inGameWorld": true, "animal": {}, "hosting": { "host": 10 } },
+                { "uid": 2, "specId": "dodo", "inGameWorld": true, "animal": {}, "hosting": { "host": 11 } },
+                { "uid": 3, "specId": "clown", "inGameWorld": true, "animal": {}, "hosting": { "host": 12 } },
+                { "uid": 4, "specId": "clown", "inGameWorld": true, "animal": { "stageNumber": 9 }, "hosting": { "host": 10 } },
+                { "uid": 5, "specId": "clown", "inGameWorld": true, "animal": {}, "hosting": { "host": 13 } },
+                { "inGameWorld": true, "tank": {} },
+                { "uid": 6, "specId": "aquarist", "inGameWorld": true, "staff": {}, "guest": {}, "tank": {} }
+            ] }"#,
+        );
+
+        let mut data = GameData::with_species(vec![test_species("clown")]);
+        data.tanks.push(crate::tank::test::test_tank_model("basic_tank"));
+
+        assert!(read_save(&data, &path).is_err());
+
+        let save = read_save_lenient(&data, &path).unwrap();
+        let warnings: Vec<String> = save.diagnostics.iter().map(|d| d.to_string()).collect();
+        assert_eq!(
+            warnings,
+            vec![
+                "tank 12 (round_tank_2_2) has no known tank model",
+                "animal 2 is of unknown species dodo in tank 11",
+                "object 4 cannot be read: stageNumber greater than number of stages!",
+                "object at index 9 cannot be read: no uid",
+                "object 6 cannot be read: no name",
+                "exhibit Reef (tank 10) is left out, not all of it could be read",
+                "exhibit Pond (tank 11) is left out, not all of it could be read",
+            ]
+        );
+        // what could be read completely is still there
+        assert_eq!(save.aquarium.exhibits.len(), 1);
+        assert_eq!(save.aquarium.exhibits[0].name, "Lagoon");
+        let ids: Vec<_> = save.aquarium.exhibits[0].animals.iter().map(|a| a.id).collect();
+        assert_eq!(ids, vec![5]);
+        assert_eq!(save.non_exhibit_animals[0].1, Hosting::Unknown);
+        // nothing of an object that couldn't be read is kept
+        assert!(save.park.staff.is_empty());
+        assert_eq!(save.park.guests, 0);
+        assert!(!save.animal_states.contains_key(&4));
+    }
+
+    #[test]
+    fn test_read_location() {
+        let tank = serde_json::json!({ "position": { "x": 12, "y": 4, "floor": 2 }, "zone": "Tropical" });
+        assert_eq!(
+            read_location(&tank).unwrap(),
+            Location {
+                floor: Some(2),
+                zone: Some("Tropical".to_string()),
+                position: Some((12, 4)),
+            }
+        );
+        assert_eq!(read_location(&serde_json::json!({})).unwrap(), Location::default());
+        assert!(read_location(&serde_json::json!({ "zone": 3 })).is_err());
+    }
+
+    #[test]
+    fn test_count_exhibits() {
+        let dir = TempDir::new("count");
+        let path = dir.save(
+            r#"{ "objects": [
+                { "uid": 10, "specId": "unknown_tank_3_3", "name": "Reef", "inGameWorld": true, "tank": {} },
+                { "uid": 11, "specId": "basic_tank_2_2", "name": "Pond", "inGameWorld": false, "tank": {} },
+                { "uid": 1, "specId": "unknown_fish", "inGameWorld": true, "animal": {}, "hosting": { "host": 10 } }
+            ] }"#,
+        );
+
+        assert_eq!(count_exhibits(&path).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_read_save_park_state() {
+        let dir = TempDir::new("park");
+        let path = dir.save(
+            r#"{ "park": { "money": -250, "prestige": 40 }, "objects": [
+                { "uid": 1, "specId": "aquarist", "name": "Ada", "inGameWorld": true, "staff": { "skills": { "feeding": 2 } } },
+                { "uid": 2, "specId": "technician", "inGameWorld": true, "staff": { "skills": { "maintenance": 3 } } },
+                { "uid": 3, "specId": "aquarist", "inGameWorld": false, "staff": {} },
+                { "uid": 4, "specId": "guest_1", "inGameWorld": true, "guest": {} }
+            ] }"#,
+        );
+
+        let data = GameData::with_species(vec![]);
+        let park = read_save(&data, &path).unwrap().park;
+
+        assert_eq!(park.money, Some(-250));
+        assert_eq!(park.prestige, Some(40));
+        assert_eq!(park.guests, 1);
+        assert_eq!(
+            park.staff,
+            vec![
+                StaffMember {
+                    id: 1,
+                    name: Some("Ada".to_string()),
+                    role: "aquarist".to_string(),
+                    feeding: 2,
+                    maintenance: 0,
+                },
+                StaffMember {
+                    id: 2,
+                    name: None,
+                    role: "technician".to_string(),
+                    feeding: 0,
+                    maintenance: 3,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_animal_state() {
+        let unhappy = serde_json::json!({ "health": 0.874, "happiness": 1.2, "unmetNeeds": ["needsLight"] });
+        assert_eq!(
+            read_animal_state(&unhappy).unwrap(),
+            AnimalState {
+                health: Some(87),
+                happiness: Some(100),
+                unmet_needs: vec!["needsLight".to_string()],
+            }
+        );
+        assert_eq!(read_animal_state(&serde_json::json!({})).unwrap(), AnimalState::default());
+        assert!(read_animal_state(&serde_json::json!({ "health": "fine" })).is_err());
+    }
+
+    #[test]
+    fn test_read_single_equipment_model() {
+        let filter = serde_json::json!({
+            "id": "canister_filter",
+            "tags": ["equipment"],
+            "lifeSupport": { "filtration": { "value": 300 }, "proteinSkimming": { "value": 20 } },
+        });
+        let model = read_single_equipment_model(&filter).unwrap().unwrap();
+        assert_eq!(model.filtration, Some(300));
+        assert_eq!(model.skimming, Some(20));
+        assert_eq!(model.heating, None);
+
+        let lamp = serde_json::json!({ "id": "lamp", "tags": ["light"], "chemistry": { "light": 2 } });
+        assert_eq!(read_single_equipment_model(&lamp).unwrap().unwrap().lighting, Some(2));
+
+        let rock = serde_json::json!({ "id": "rock", "tags": ["scenery"] });
+        assert_eq!(read_single_equipment_model(&rock).unwrap(), None);
+    }
+
     #[test]
     fn test_read_game_data() {
-        let data = read_game_data().unwrap();
+        let data = installed_game_data();
         let species = data.species_ref("11_yellow_tang").unwrap();
         assert_eq!(species.size.final_size, 5);
     }
 
     #[test]
     fn test_feeding_skill_ac1_1_skill_loaded_from_game_data() {
-        let data = read_game_data().unwrap();
+        let data = installed_game_data();
         let species = data.species_ref("33_arabian_butterflyfish").unwrap();
         match &species.diet {
             Diet::Food { skill, .. } => {
@@ -872,7 +2199,7 @@ mod test {
 
     #[test]
     fn test_feeding_skill_ac1_2_skill_defaults_to_zero() {
-        let data = read_game_data().unwrap();
+        let data = installed_game_data();
         let species = data.species_ref("1_azure_demoiselle").unwrap();
         match &species.diet {
             Diet::Food { skill, .. } => {
@@ -887,7 +2214,7 @@ mod test {
 
     #[test]
     fn test_feeding_skill_ac1_3_other_diet_types_unaffected() {
-        let data = read_game_data().unwrap();
+        let data = installed_game_data();
 
         // Scavenger variant should be unaffected
         let scavenger = data.species_ref("53_blue_knuckle_hermit_crab").unwrap();
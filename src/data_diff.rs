@@ -0,0 +1,315 @@
+// pattern: Functional Core
+
+// Compares two versions of the game data (e.g., before and after a patch) so we can tell which
+// plans a balance change affects. Entities are compared through their serde representation, which
+// gives us field-by-field differences for free, named after the struct fields.
+
+use crate::aquarium::AquariumDesc;
+use crate::check::{validate_aquarium, ValidateArgs};
+use crate::data::GameData;
+use crate::rules::RuleOptions;
+
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Debug, PartialEq)]
+pub struct FieldChange {
+    /// dotted path to the field, e.g. `habitat.minimum_quality` or `diet.Food.period`
+    pub field: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Change {
+    Added,
+    Removed,
+    Changed(Vec<FieldChange>),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct EntityChange {
+    pub kind: &'static str,
+    pub id: String,
+    pub change: Change,
+}
+
+pub fn diff_game_data(old: &GameData, new: &GameData) -> Vec<EntityChange> {
+    let mut result = Vec::new();
+    diff_entities(&mut result, "species", &old.species, &new.species, |s| &s.id);
+    diff_entities(&mut result, "tank", &old.tanks, &new.tanks, |t| &t.id);
+    diff_entities(&mut result, "fixture", &old.fixtures, &new.fixtures, |f| &f.id);
+    diff_entities(&mut result, "equipment", &old.equipment, &new.equipment, |e| &e.id);
+    diff_entities(&mut result, "food", &old.food, &new.food, |f| &f.id);
+    result
+}
+
+fn diff_entities<T: Serialize>(result: &mut Vec<EntityChange>, kind: &'static str, old: &[T], new: &[T], id: fn(&T) -> &String) {
+    for o in old {
+        let change = match new.iter().find(|n| id(n) == id(o)) {
+            None => Some(Change::Removed),
+            Some(n) => {
+                let mut fields = Vec::new();
+                diff_values(&mut fields, "", &as_value(o), &as_value(n));
+                if fields.is_empty() {
+                    None
+                } else {
+                    Some(Change::Changed(fields))
+                }
+            }
+        };
+
+        if let Some(change) = change {
+            result.push(EntityChange {
+                kind,
+                id: id(o).clone(),
+                change,
+            });
+        }
+    }
+
+    for n in new {
+        if !old.iter().any(|o| id(o) == id(n)) {
+            result.push(EntityChange {
+                kind,
+                id: id(n).clone(),
+                change: Change::Added,
+            });
+        }
+    }
+}
+
+fn as_value<T: Serialize>(x: &T) -> Value {
+    serde_json::to_value(x).expect("game data is representable as json")
+}
+
+/// Walks into objects so changes are reported at the innermost field; anything else (numbers,
+/// lists, an enum switching variants) is reported as a whole.
+fn diff_values(result: &mut Vec<FieldChange>, path: &str, old: &Value, new: &Value) {
+    match (old, new) {
+        (Value::Object(a), Value::Object(b)) => {
+            let mut keys: Vec<&String> = a.keys().chain(b.keys()).collect();
+            keys.sort();
+            keys.dedup();
+
+            for k in keys {
+                let field = if path.is_empty() { k.clone() } else { format!("{}.{}", path, k) };
+                diff_values(result, &field, a.get(k).unwrap_or(&Value::Null), b.get(k).unwrap_or(&Value::Null));
+            }
+        }
+        _ => {
+            if old != new {
+                result.push(FieldChange {
+                    field: path.to_string(),
+                    old: old.clone(),
+                    new: new.clone(),
+                });
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Valid,
+    /// the distinct violation messages
+    Invalid(Vec<String>),
+    /// the plan refers to something this version of the data doesn't have
+    Unloadable(String),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct VerdictChange {
+    pub exhibit: String,
+    pub old: Verdict,
+    pub new: Verdict,
+}
+
+/// Validates every exhibit of the plan under both versions of the data and returns the ones whose
+/// outcome differs. Exhibits are validated one at a time so one exhibit with, e.g., a removed
+/// species doesn't hide changes to the others.
+pub fn verdict_changes(old: &GameData, new: &GameData, aquarium: &AquariumDesc, options: &RuleOptions) -> Vec<VerdictChange> {
+    let mut result = Vec::new();
+
+    for exhibit in &aquarium.exhibits {
+        let single = AquariumDesc {
+            exhibits: vec![exhibit.clone()],
+            elsewhere: vec![],
+        };
+        let old_verdict = verdict(old, &single, options);
+        let new_verdict = verdict(new, &single, options);
+
+        if old_verdict != new_verdict {
+            result.push(VerdictChange {
+                exhibit: exhibit.name.clone(),
+                old: old_verdict,
+                new: new_verdict,
+            });
+        }
+    }
+
+    result
+}
+
+fn verdict(data: &GameData, aquarium: &AquariumDesc, options: &RuleOptions) -> Verdict {
+    let aquarium = match aquarium.to_ref(data, options) {
+        Ok(a) => a,
+        Err(e) => return Verdict::Unloadable(e.to_string()),
+    };
+
+    let result = validate_aquarium(data, &ValidateArgs { aquarium: &aquarium });
+
+    let mut messages: Vec<String> = result.exhibits.iter().flat_map(|e| &e.violations).map(|v| v.to_string()).collect();
+    messages.sort();
+    messages.dedup();
+
+    if messages.is_empty() {
+        Verdict::Valid
+    } else {
+        Verdict::Invalid(messages)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::animal::test::test_species;
+    use crate::animal::*;
+    use crate::aquarium::{AnimalDesc, ExhibitDesc, SpeciesCount};
+    use crate::food::test::test_food;
+    use crate::tank::test::test_tank_model;
+    use crate::tank::Tank;
+    use serde_json::json;
+
+    fn test_data(species: Vec<Species>) -> GameData {
+        GameData {
+            species,
+            tanks: vec![test_tank_model("basic_tank")],
+            food: vec![test_food("flakes")],
+            ..GameData::default()
+        }
+    }
+
+    #[test]
+    fn test_field_changes() {
+        let old = test_species("tang");
+        let mut new = test_species("tang");
+        new.habitat.minimum_quality = 70;
+        new.diet = Diet::Food {
+            food: "flakes".to_string(),
+            period: 2,
+            skill: 0,
+        };
+
+        let changes = diff_game_data(&test_data(vec![old]), &test_data(vec![new]));
+
+        assert_eq!(
+            changes,
+            vec![EntityChange {
+                kind: "species",
+                id: "tang".to_string(),
+                change: Change::Changed(vec![
+                    FieldChange {
+                        field: "diet".to_string(),
+                        old: json!("DoesNotEat"),
+                        new: json!({"Food": {"food": "flakes", "period": 2, "skill": 0}}),
+                    },
+                    FieldChange {
+                        field: "habitat.minimum_quality".to_string(),
+                        old: json!(55),
+                        new: json!(70),
+                    },
+                ]),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_nested_enum_field() {
+        let food = |period| Diet::Food {
+            food: "flakes".to_string(),
+            period,
+            skill: 0,
+        };
+        let old = Species {
+            diet: food(2),
+            ..test_species("tang")
+        };
+        let new = Species {
+            diet: food(3),
+            ..test_species("tang")
+        };
+
+        let changes = diff_game_data(&test_data(vec![old]), &test_data(vec![new]));
+
+        match &changes[0].change {
+            Change::Changed(fields) => assert_eq!(fields[0].field, "diet.Food.period"),
+            c => panic!("unexpected change {:?}", c),
+        }
+    }
+
+    #[test]
+    fn test_added_and_removed() {
+        let mut new = test_data(vec![test_species("b")]);
+        new.food.clear();
+
+        let changes = diff_game_data(&test_data(vec![test_species("a")]), &new);
+        let summary: Vec<_> = changes.iter().map(|c| (c.kind, c.id.as_str(), &c.change)).collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("species", "a", &Change::Removed),
+                ("species", "b", &Change::Added),
+                ("food", "flakes", &Change::Removed),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verdict_changes() {
+        let plan = AquariumDesc {
+            exhibits: vec![ExhibitDesc {
+                name: "Reef".to_string(),
+                tank: Tank {
+                    id: 1,
+                    model: "basic_tank".to_string(),
+                    size: (4, 4),
+                },
+                animals: vec![AnimalDesc::Summary(SpeciesCount {
+                    species: "tang".to_string(),
+                    count: 1,
+                })],
+                fixtures: vec![],
+                equipment: vec![],
+                location: Default::default(),
+                names: Default::default(),
+            }],
+            elsewhere: vec![],
+        };
+        let options = RuleOptions {
+            assume_all_fish_fully_grown: true,
+        };
+
+        let old = test_data(vec![test_species("tang")]);
+        let new = test_data(vec![Species {
+            shoaling: Some(Shoaling {
+                count: 3,
+                one_ok: false,
+                two_ok: false,
+            }),
+            ..test_species("tang")
+        }]);
+
+        assert_eq!(verdict_changes(&old, &old, &plan, &options), vec![]);
+
+        let changes = verdict_changes(&old, &new, &plan, &options);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].old, Verdict::Valid);
+        assert!(matches!(&changes[0].new, Verdict::Invalid(m) if m[0].contains("shoaler")));
+
+        let gone = test_data(vec![]);
+        let changes = verdict_changes(&old, &gone, &plan, &options);
+        assert!(matches!(&changes[0].new, Verdict::Unloadable(_)));
+    }
+}
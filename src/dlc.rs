@@ -0,0 +1,88 @@
+// pattern: Functional Core
+
+use crate::util::as_str_display;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Dlc {
+    FreshwaterFrenzy,
+    ArchitectsCollection,
+}
+
+pub const ALL_DLC: &[Dlc] = &[Dlc::FreshwaterFrenzy, Dlc::ArchitectsCollection];
+
+impl Dlc {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Dlc::FreshwaterFrenzy => "freshwater-frenzy",
+            Dlc::ArchitectsCollection => "architects-collection",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Dlc> {
+        ALL_DLC.iter().copied().find(|d| d.as_str() == s)
+    }
+
+    /// Where the DLC's files live, relative to the game data directory.
+    pub fn directory(&self) -> &'static str {
+        match self {
+            Dlc::FreshwaterFrenzy => "DLC/Freshwater Frenzy",
+            Dlc::ArchitectsCollection => "DLC/Architect's Collection",
+        }
+    }
+}
+
+as_str_display!(Dlc);
+
+/// Which DLC content the user wants considered. Base game content is always allowed.
+#[derive(Debug, Clone, Default)]
+pub struct DlcSelection {
+    /// if non-empty, only these DLC are allowed
+    pub only: Vec<Dlc>,
+    pub excluded: Vec<Dlc>,
+}
+
+impl DlcSelection {
+    pub fn allows(&self, dlc: Option<Dlc>) -> bool {
+        match dlc {
+            None => true,
+            Some(d) => (self.only.is_empty() || self.only.contains(&d)) && !self.excluded.contains(&d),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_str_roundtrip() {
+        for d in ALL_DLC {
+            assert_eq!(Dlc::from_str(d.as_str()), Some(*d));
+        }
+        assert_eq!(Dlc::from_str("aqua-vita"), None);
+    }
+
+    #[test]
+    fn test_selection() {
+        let everything = DlcSelection::default();
+        assert!(everything.allows(None));
+        assert!(everything.allows(Some(Dlc::FreshwaterFrenzy)));
+
+        let only_ff = DlcSelection {
+            only: vec![Dlc::FreshwaterFrenzy],
+            excluded: vec![],
+        };
+        assert!(only_ff.allows(None));
+        assert!(only_ff.allows(Some(Dlc::FreshwaterFrenzy)));
+        assert!(!only_ff.allows(Some(Dlc::ArchitectsCollection)));
+
+        let no_ff = DlcSelection {
+            only: vec![],
+            excluded: vec![Dlc::FreshwaterFrenzy],
+        };
+        assert!(no_ff.allows(None));
+        assert!(!no_ff.allows(Some(Dlc::FreshwaterFrenzy)));
+        assert!(no_ff.allows(Some(Dlc::ArchitectsCollection)));
+    }
+}
@@ -0,0 +1,75 @@
+// pattern: Functional Core
+
+use serde::{Deserialize, Serialize};
+
+/// What something costs to buy and to keep, and how much prestige it brings. Not every object in
+/// the game data has all of these, so each one is optional.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Economy {
+    /// purchase price
+    pub cost: Option<u32>,
+    /// running cost per day
+    pub upkeep: Option<u32>,
+    pub prestige: Option<u16>,
+}
+
+/// Totals over everything in an exhibit, where missing values count as zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ExhibitEconomy {
+    pub build_cost: u32,
+    /// upkeep plus food, per day
+    pub running_cost: u32,
+    pub prestige: u32,
+}
+
+impl ExhibitEconomy {
+    pub fn of<'a, I: IntoIterator<Item = &'a Economy>>(items: I, food_cost: u32) -> ExhibitEconomy {
+        let mut result = ExhibitEconomy {
+            running_cost: food_cost,
+            ..ExhibitEconomy::default()
+        };
+
+        for e in items {
+            result.build_cost += e.cost.unwrap_or(0);
+            result.running_cost += e.upkeep.unwrap_or(0);
+            result.prestige += e.prestige.unwrap_or(0) as u32;
+        }
+
+        result
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == ExhibitEconomy::default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_exhibit_economy_sums_known_values() {
+        let fish = Economy {
+            cost: Some(180),
+            upkeep: None,
+            prestige: Some(4),
+        };
+        let tank = Economy {
+            cost: Some(500),
+            upkeep: Some(3),
+            prestige: None,
+        };
+
+        let result = ExhibitEconomy::of([&fish, &fish, &tank], 10);
+
+        assert_eq!(
+            result,
+            ExhibitEconomy {
+                build_cost: 860,
+                running_cost: 13,
+                prestige: 8,
+            }
+        );
+        assert!(ExhibitEconomy::of([&Economy::default()], 0).is_empty());
+    }
+}
@@ -0,0 +1,124 @@
+// pattern: Functional Core
+
+use crate::dlc::Dlc;
+use crate::names::Names;
+use serde::{Deserialize, Serialize};
+
+pub type EquipmentId = u64;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equipment {
+    pub id: EquipmentId,
+    pub model: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EquipmentRef<'a> {
+    pub id: EquipmentId,
+    pub model: &'a EquipmentModel,
+}
+
+/// Life support equipment: filters, heaters, chillers, protein skimmers and lights.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EquipmentModel {
+    pub id: String,
+    /// how much bioload it can filter
+    pub filtration: Option<u16>,
+    pub heating: Option<u16>,
+    pub cooling: Option<u16>,
+    pub skimming: Option<u16>,
+    pub lighting: Option<u8>,
+    pub names: Names,
+    pub dlc: Option<Dlc>,
+    pub mod_name: Option<String>,
+}
+
+/// The combined capacity of all the equipment serving one tank.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LifeSupport {
+    pub filtration: u16,
+    pub heating: u16,
+    pub cooling: u16,
+    pub skimming: u16,
+    pub lighting: u16,
+}
+
+impl LifeSupport {
+    pub fn of(equipment: &[EquipmentRef]) -> LifeSupport {
+        // saturating, so a huge exhibit or a modded item with big numbers can't overflow
+        let sum = |f: fn(&EquipmentModel) -> Option<u16>| {
+            equipment
+                .iter()
+                .fold(0u16, |total, e| total.saturating_add(f(e.model).unwrap_or(0)))
+        };
+
+        LifeSupport {
+            filtration: sum(|m| m.filtration),
+            heating: sum(|m| m.heating),
+            cooling: sum(|m| m.cooling),
+            skimming: sum(|m| m.skimming),
+            lighting: sum(|m| m.lighting.map(|l| l as u16)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == LifeSupport::default()
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    pub fn test_equipment_model<S: Into<String>>(id: S) -> EquipmentModel {
+        EquipmentModel {
+            id: id.into(),
+            filtration: None,
+            heating: None,
+            cooling: None,
+            skimming: None,
+            lighting: None,
+            names: Names::default(),
+            dlc: None,
+            mod_name: None,
+        }
+    }
+
+    #[test]
+    fn test_life_support_sums_equipment() {
+        let filter = EquipmentModel {
+            filtration: Some(60),
+            ..test_equipment_model("filter")
+        };
+        let heater = EquipmentModel {
+            heating: Some(2),
+            lighting: Some(1),
+            ..test_equipment_model("heater")
+        };
+        let equipment = vec![
+            EquipmentRef { id: 1, model: &filter },
+            EquipmentRef { id: 2, model: &filter },
+            EquipmentRef { id: 3, model: &heater },
+        ];
+
+        let result = LifeSupport::of(&equipment);
+
+        assert_eq!(result.filtration, 120);
+        assert_eq!(result.heating, 2);
+        assert_eq!(result.cooling, 0);
+        assert_eq!(result.lighting, 1);
+        assert!(!result.is_empty());
+        assert!(LifeSupport::of(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_life_support_saturates() {
+        let pump = EquipmentModel {
+            filtration: Some(u16::MAX - 10),
+            ..test_equipment_model("pump")
+        };
+        let equipment = vec![EquipmentRef { id: 1, model: &pump }, EquipmentRef { id: 2, model: &pump }];
+
+        assert_eq!(LifeSupport::of(&equipment).filtration, u16::MAX);
+    }
+}
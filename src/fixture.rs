@@ -1,6 +1,11 @@
+use crate::dlc::Dlc;
+use crate::economy::Economy;
+use crate::names::Names;
+use serde::{Deserialize, Serialize};
+
 pub type FixtureId = u64;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Fixture {
     pub id: FixtureId,
     pub model: String,
@@ -12,7 +17,15 @@ pub struct FixtureRef<'a> {
     pub model: &'a FixtureModel,
 }
 
-#[derive(Debug)]
+/// An object from the fixture files that isn't loaded as a fixture, so `extract` can explain why a
+/// save's decoration contributes nothing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkippedFixture {
+    pub id: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct FixtureModel {
     pub id: String,
     pub light: Option<u8>,
@@ -23,4 +36,33 @@ pub struct FixtureModel {
     pub flat_surfaces: Option<u8>,
     pub vertical_surfaces: Option<u8>,
     pub fluffy_foliage: Option<u8>,
+    pub names: Names,
+    pub economy: Economy,
+    pub unlock_level: Option<u8>,
+    pub dlc: Option<Dlc>,
+    pub mod_name: Option<String>,
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    pub fn test_fixture_model<S: Into<String>>(id: S) -> FixtureModel {
+        FixtureModel {
+            id: id.into(),
+            light: None,
+            plants: None,
+            rocks: None,
+            caves: None,
+            bogwood: None,
+            flat_surfaces: None,
+            vertical_surfaces: None,
+            fluffy_foliage: None,
+            economy: Economy::default(),
+            names: Names::default(),
+            unlock_level: None,
+            dlc: None,
+            mod_name: None,
+        }
+    }
 }
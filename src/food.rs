@@ -0,0 +1,36 @@
+// pattern: Functional Core
+
+use crate::dlc::Dlc;
+use crate::names::Names;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FoodModel {
+    pub id: String,
+    /// price of a single portion
+    pub cost: Option<u32>,
+    /// how it has to be kept, e.g. `frozen`
+    pub storage: Option<String>,
+    /// the station that has to prepare it before staff can feed it, if any
+    pub preparation: Option<String>,
+    pub names: Names,
+    pub dlc: Option<Dlc>,
+    pub mod_name: Option<String>,
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    pub fn test_food<S: Into<String>>(id: S) -> FoodModel {
+        FoodModel {
+            id: id.into(),
+            cost: None,
+            storage: None,
+            preparation: None,
+            names: Names::default(),
+            dlc: None,
+            mod_name: None,
+        }
+    }
+}
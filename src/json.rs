@@ -0,0 +1,389 @@
+// pattern: Functional Core
+
+// The game's .data and .sav files are "json" as far as Unity's parser is concerned, which means
+// comments, trailing commas and raw newlines inside strings (e.g., the "map" of a tank) all show
+// up. serde's parser rejects all of those, so this is a small lenient reader that produces the
+// same serde_json::Value the loaders already work with.
+
+use serde_json::{Map, Number, Value};
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl JsonError {
+    pub fn in_file<S: Into<String>>(mut self, file: S) -> JsonError {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}: {}", file, self.line, self.column, self.message),
+            None => write!(f, "line {} column {}: {}", self.line, self.column, self.message),
+        }
+    }
+}
+
+impl Error for JsonError {}
+
+pub fn from_str(text: &str) -> Result<Value, JsonError> {
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        pos: 0,
+        line: 1,
+        column: 1,
+    };
+
+    parser.skip_whitespace()?;
+    let value = parser.parse_value()?;
+    parser.skip_whitespace()?;
+
+    match parser.peek() {
+        None => Ok(value),
+        Some(c) => Err(parser.error(format!("unexpected '{}' after end of document", c))),
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Parser {
+    fn error<S: Into<String>>(&self, message: S) -> JsonError {
+        JsonError {
+            file: None,
+            line: self.line,
+            column: self.column,
+            message: message.into(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), JsonError> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.next();
+                Ok(())
+            }
+            Some(c) => Err(self.error(format!("expected '{}' but found '{}'", expected, c))),
+            None => Err(self.error(format!("expected '{}' but found end of file", expected))),
+        }
+    }
+
+    /// Skips whitespace as well as `//` and `/* */` comments.
+    fn skip_whitespace(&mut self) -> Result<(), JsonError> {
+        loop {
+            match (self.peek(), self.peek_at(1)) {
+                (Some(c), _) if c.is_whitespace() || c == '\u{feff}' => {
+                    self.next();
+                }
+                (Some('/'), Some('/')) => {
+                    while let Some(c) = self.next() {
+                        if c == '\n' {
+                            break;
+                        }
+                    }
+                }
+                (Some('/'), Some('*')) => {
+                    let start = self.error("unterminated block comment");
+                    self.next();
+                    self.next();
+                    loop {
+                        match (self.peek(), self.peek_at(1)) {
+                            (Some('*'), Some('/')) => {
+                                self.next();
+                                self.next();
+                                break;
+                            }
+                            (Some(_), _) => {
+                                self.next();
+                            }
+                            (None, _) => return Err(start),
+                        }
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value, JsonError> {
+        match self.peek() {
+            Some('{') => self.parse_object(),
+            Some('[') => self.parse_array(),
+            Some('"') => Ok(Value::String(self.parse_string()?)),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number(),
+            Some(c) if c.is_alphabetic() => self.parse_literal(),
+            Some(c) => Err(self.error(format!("unexpected '{}'", c))),
+            None => Err(self.error("unexpected end of file")),
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Value, JsonError> {
+        self.expect('{')?;
+        let mut map = Map::new();
+
+        loop {
+            self.skip_whitespace()?;
+            if self.peek() == Some('}') {
+                self.next();
+                return Ok(Value::Object(map));
+            }
+
+            let key = match self.peek() {
+                Some('"') => self.parse_string()?,
+                Some(c) => return Err(self.error(format!("expected object key but found '{}'", c))),
+                None => return Err(self.error("unterminated object")),
+            };
+
+            self.skip_whitespace()?;
+            self.expect(':')?;
+            self.skip_whitespace()?;
+            let value = self.parse_value()?;
+            map.insert(key, value);
+
+            self.skip_whitespace()?;
+            match self.peek() {
+                // a trailing comma before the '}' is fine, the top of the loop handles it
+                Some(',') => {
+                    self.next();
+                }
+                Some('}') => (),
+                Some(c) => return Err(self.error(format!("expected ',' or '}}' but found '{}'", c))),
+                None => return Err(self.error("unterminated object")),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Value, JsonError> {
+        self.expect('[')?;
+        let mut list = Vec::new();
+
+        loop {
+            self.skip_whitespace()?;
+            if self.peek() == Some(']') {
+                self.next();
+                return Ok(Value::Array(list));
+            }
+
+            list.push(self.parse_value()?);
+
+            self.skip_whitespace()?;
+            match self.peek() {
+                Some(',') => {
+                    self.next();
+                }
+                Some(']') => (),
+                Some(c) => return Err(self.error(format!("expected ',' or ']' but found '{}'", c))),
+                None => return Err(self.error("unterminated array")),
+            }
+        }
+    }
+
+    /// Parses a quoted string. Unlike strict json, raw newlines and tabs are allowed inside.
+    fn parse_string(&mut self) -> Result<String, JsonError> {
+        let start = self.error("unterminated string");
+        self.expect('"')?;
+        let mut result = String::new();
+
+        loop {
+            match self.next() {
+                None => return Err(start),
+                Some('"') => return Ok(result),
+                Some('\\') => {
+                    let escape = self.error("bad escape sequence");
+                    match self.next() {
+                        Some('"') => result.push('"'),
+                        Some('\\') => result.push('\\'),
+                        Some('/') => result.push('/'),
+                        Some('b') => result.push('\u{8}'),
+                        Some('f') => result.push('\u{c}'),
+                        Some('n') => result.push('\n'),
+                        Some('r') => result.push('\r'),
+                        Some('t') => result.push('\t'),
+                        Some('u') => result.push(self.parse_unicode_escape()?),
+                        _ => return Err(escape),
+                    }
+                }
+                Some(c) => result.push(c),
+            }
+        }
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, JsonError> {
+        let mut result = 0;
+        for _ in 0..4 {
+            let digit = self
+                .peek()
+                .and_then(|c| c.to_digit(16))
+                .ok_or_else(|| self.error("expected hex digit"))?;
+            self.next();
+            result = result * 16 + digit;
+        }
+        Ok(result)
+    }
+
+    fn parse_unicode_escape(&mut self) -> Result<char, JsonError> {
+        let first = self.parse_hex4()?;
+
+        let code = if (0xD800..0xDC00).contains(&first) {
+            // high surrogate, must be followed by an escaped low surrogate
+            if self.peek() != Some('\\') || self.peek_at(1) != Some('u') {
+                return Err(self.error("unpaired surrogate in unicode escape"));
+            }
+            self.next();
+            self.next();
+            let second = self.parse_hex4()?;
+            if !(0xDC00..0xE000).contains(&second) {
+                return Err(self.error("unpaired surrogate in unicode escape"));
+            }
+            0x10000 + ((first - 0xD800) << 10) + (second - 0xDC00)
+        } else {
+            first
+        };
+
+        char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"))
+    }
+
+    fn parse_number(&mut self) -> Result<Value, JsonError> {
+        let error = self.error("invalid number");
+        let mut text = String::new();
+
+        while let Some(c) = self.peek() {
+            if c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E') {
+                text.push(c);
+                self.next();
+            } else {
+                break;
+            }
+        }
+
+        let number = if let Ok(n) = text.parse::<u64>() {
+            Some(Number::from(n))
+        } else if let Ok(n) = text.parse::<i64>() {
+            Some(Number::from(n))
+        } else {
+            text.parse::<f64>().ok().and_then(Number::from_f64)
+        };
+
+        number.map(Value::Number).ok_or(error)
+    }
+
+    fn parse_literal(&mut self) -> Result<Value, JsonError> {
+        let error = self.error("unknown literal");
+        let mut word = String::new();
+
+        while let Some(c) = self.peek() {
+            if c.is_alphanumeric() {
+                word.push(c);
+                self.next();
+            } else {
+                break;
+            }
+        }
+
+        match word.as_str() {
+            "true" => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            "null" => Ok(Value::Null),
+            _ => Err(JsonError {
+                message: format!("unknown literal '{}'", word),
+                ..error
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_strict_json() {
+        let value = from_str(r#"{"a": [1, -2, 3.5, true, false, null], "b": {"c": "d"}}"#).unwrap();
+        assert_eq!(value, json!({"a": [1, -2, 3.5, true, false, null], "b": {"c": "d"}}));
+    }
+
+    #[test]
+    fn test_comments() {
+        let text = "{\n  // line comment\n  \"a\": 1, /* block\n comment */ \"b\": 2 // trailing\n}";
+        assert_eq!(from_str(text).unwrap(), json!({"a": 1, "b": 2}));
+    }
+
+    #[test]
+    fn test_comment_markers_inside_strings_are_kept() {
+        let text = r#"{"url": "http://example.com", "glob": "a/*b*/c"}"#;
+        assert_eq!(from_str(text).unwrap(), json!({"url": "http://example.com", "glob": "a/*b*/c"}));
+    }
+
+    #[test]
+    fn test_trailing_commas() {
+        let text = "{\"a\": [1, 2, ], \"b\": {\"c\": 3,\n},\n}";
+        assert_eq!(from_str(text).unwrap(), json!({"a": [1, 2], "b": {"c": 3}}));
+    }
+
+    #[test]
+    fn test_multiline_strings() {
+        let text = "{\"map\": \"xx\nx//x\n\"}";
+        assert_eq!(from_str(text).unwrap(), json!({"map": "xx\nx//x\n"}));
+    }
+
+    #[test]
+    fn test_escapes() {
+        let text = r#"["a\"b\\c\n", "é🐟"]"#;
+        assert_eq!(from_str(text).unwrap(), json!(["a\"b\\c\n", "é🐟"]));
+    }
+
+    #[test]
+    fn test_error_position() {
+        let err = from_str("{\n  \"a\": 1\n  \"b\": 2\n}").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(
+            err.in_file("Data/animals.data").to_string(),
+            "Data/animals.data:3:3: expected ',' or '}' but found '\"'"
+        );
+    }
+
+    #[test]
+    fn test_unterminated_string_reports_start() {
+        let err = from_str("[1,\n \"abc").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn test_trailing_garbage() {
+        assert!(from_str("{} x").is_err());
+    }
+}
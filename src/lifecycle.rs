@@ -0,0 +1,137 @@
+// pattern: Functional Core
+
+use crate::animal::{Breeding, Species};
+use crate::data::GameData;
+use crate::util::{error, Result};
+
+#[derive(Debug, PartialEq)]
+pub struct LifeStage<'a> {
+    pub species: &'a Species,
+    pub size: u16,
+    /// in days, None for the adult's final size or when the game data doesn't say
+    pub duration: Option<u16>,
+}
+
+/// Every stage an animal goes through, from the egg or fry its parents produce up to the adult's
+/// final size. Species that don't breed only have their own growth stages.
+pub fn life_cycle<'a>(data: &'a GameData, adult: &'a Species) -> Result<Vec<LifeStage<'a>>> {
+    let mut forms: Vec<&Species> = Vec::new();
+
+    let mut next = match &adult.breeding {
+        Breeding::Breedable(b) => Some(b.baby.clone()),
+        _ => None,
+    };
+
+    while let Some(id) = next {
+        if id == adult.id {
+            break;
+        }
+        if forms.iter().any(|f| f.id == id) {
+            return Err(error(format!("the life cycle of {} loops at {}", adult.id, id)));
+        }
+
+        let form = data.species_ref(&id)?;
+        forms.push(form);
+
+        next = match &form.breeding {
+            Breeding::NotFullyGrown(young) => young.grows_into.clone(),
+            _ => None,
+        };
+    }
+
+    let mut result = Vec::new();
+
+    for species in forms.into_iter().chain(std::iter::once(adult)) {
+        for stage in &species.size.stages {
+            result.push(LifeStage {
+                species,
+                size: stage.size,
+                duration: Some(stage.duration),
+            });
+        }
+
+        result.push(LifeStage {
+            species,
+            size: species.size.final_size,
+            duration: match &species.breeding {
+                Breeding::NotFullyGrown(young) => young.duration,
+                _ => None,
+            },
+        });
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::animal::test::test_species;
+    use crate::animal::*;
+
+    fn young(id: &str, grows_into: Option<&str>, size: u16, duration: u16) -> Species {
+        let mut s = test_species(id);
+        s.size.final_size = size;
+        s.breeding = Breeding::NotFullyGrown(Young {
+            grows_into: grows_into.map(|g| g.to_string()),
+            duration: Some(duration),
+        });
+        s
+    }
+
+    #[test]
+    fn test_life_cycle_follows_young_forms() {
+        let mut adult = test_species("clown");
+        adult.size.stages = vec![Stage { size: 3, duration: 10 }];
+        adult.breeding = Breeding::Breedable(Breedable {
+            baby: "clown.egg".to_string(),
+            interval: Some(20),
+            conditions: vec![],
+        });
+        let data = GameData::with_species(vec![
+            adult,
+            young("clown.egg", Some("clown.fry"), 1, 2),
+            young("clown.fry", Some("clown"), 2, 4),
+        ]);
+
+        let stages = life_cycle(&data, &data.species[0]).unwrap();
+        let summary: Vec<_> = stages.iter().map(|s| (s.species.id.as_str(), s.size, s.duration)).collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("clown.egg", 1, Some(2)),
+                ("clown.fry", 2, Some(4)),
+                ("clown", 3, Some(10)),
+                ("clown", 5, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_life_cycle_of_non_breeder() {
+        let data = GameData::with_species(vec![test_species("tang")]);
+
+        let stages = life_cycle(&data, &data.species[0]).unwrap();
+
+        assert_eq!(stages.len(), 1);
+        assert_eq!(stages[0].size, 5);
+    }
+
+    #[test]
+    fn test_life_cycle_loop_is_an_error() {
+        let mut adult = test_species("clown");
+        adult.breeding = Breeding::Breedable(Breedable {
+            baby: "clown.egg".to_string(),
+            interval: None,
+            conditions: vec![],
+        });
+        let data = GameData::with_species(vec![
+            adult,
+            young("clown.egg", Some("clown.fry"), 1, 2),
+            young("clown.fry", Some("clown.egg"), 2, 4),
+        ]);
+
+        assert!(life_cycle(&data, &data.species[0]).is_err());
+    }
+}
@@ -4,38 +4,109 @@
 
 mod animal;
 mod aquarium;
+mod audit;
+mod cache;
 mod check;
+mod compare;
 mod data;
+mod data_diff;
+mod dlc;
+mod economy;
+mod equipment;
 mod fixture;
+mod food;
+mod json;
+mod lifecycle;
+mod names;
+mod park;
 mod paths;
+mod progress;
 mod report;
 mod rules;
+mod save_diff;
 mod sexpr_format;
 mod sexpr_impl;
 mod tank;
 mod util;
+mod watch;
+mod water;
 
 use aquarium::*;
 use check::*;
 use clap::{Parser, ValueEnum};
 use data::*;
+use dlc::DlcSelection;
 use report::*;
 use sexpr_format::*;
 use std::error::Error;
+use std::path::{Path, PathBuf};
 
 use crate::rules::{ExhibitSpec, RuleOptions};
 
 fn main() {
     let opts = Opts::parse();
-    let data = read_game_data().unwrap();
+
+    // cache maintenance shouldn't require (or load) the game data
+    if let SubCommand::Cache(c) = &opts.command {
+        run_cache_command(c, &opts);
+        return;
+    }
+
+    // comparing game versions brings its own data directories
+    if let SubCommand::DataDiff(d) = &opts.command {
+        match run_data_diff(d, &opts) {
+            Ok(_) => (),
+            Err(error) => {
+                println!("{}", error);
+                std::process::exit(2);
+            }
+        }
+        return;
+    }
+
+    let data_dir = or_exit(paths::find_data_dir(opts.data_dir.as_deref()));
+    let mod_dirs = or_exit(paths::find_mod_dirs(&data_dir, &opts.mod_dir, !opts.no_mods));
+
+    // the audit looks at the raw files, so it still works when a patch breaks the loaders
+    if let SubCommand::AuditData(a) = &opts.command {
+        let objects = or_exit(read_raw_objects(&data_dir, &mod_dirs));
+        print_audit(&audit::audit_objects(&objects), a.all);
+        return;
+    }
+    let data = or_exit(cache::load_game_data(&data_dir, &mod_dirs, !opts.no_cache));
+
+    let installed_dlc = paths::installed_dlc(&data_dir);
+    let selection = DlcSelection {
+        only: opts.dlc.clone(),
+        excluded: opts.no_dlc.clone(),
+    };
+    for d in opts.dlc.iter().chain(&opts.no_dlc) {
+        if !installed_dlc.contains(d) {
+            eprintln!("warning: DLC {} is not installed in {}", d, data_dir.display());
+        }
+    }
+
+    let lang = opts.lang.as_deref().unwrap_or(names::DEFAULT_LANGUAGE);
+    if opts.lang.is_some() && !data.languages().contains(&lang) {
+        eprintln!("warning: the game data has no names in language {}", lang);
+    }
 
     match opts.command {
         SubCommand::Lookup(l) => {
+            let data = or_exit(restrict_for_planning(
+                data,
+                &selection,
+                opts.available_only.as_deref(),
+                opts.save_dir.as_deref(),
+            ));
             let mut did_write = false;
 
+            let terms = [l.search_term.as_str()];
+
             for s in data.species {
-                if s.id.contains(&l.search_term) {
+                if s.id.contains(&l.search_term) || s.names.matches(&terms) {
                     did_write = true;
+                    println!("{}:", named(&s.id, &s.names, lang));
                     if l.debug {
                         println!("{:#?}", s);
                     } else {
@@ -45,8 +116,9 @@ fn main() {
             }
 
             for t in data.tanks {
-                if t.id.contains(&l.search_term) {
+                if t.id.contains(&l.search_term) || t.names.matches(&terms) {
                     did_write = true;
+                    println!("{}:", named(&t.id, &t.names, lang));
                     if l.debug {
                         println!("{:#?}", t);
                     } else {
@@ -56,8 +128,33 @@ fn main() {
             }
 
             for f in data.fixtures {
-                if f.id.contains(&l.search_term) {
+                if f.id.contains(&l.search_term) || f.names.matches(&terms) {
+                    did_write = true;
+                    println!("{}:", named(&f.id, &f.names, lang));
+                    if l.debug {
+                        println!("{:#?}", f);
+                    } else {
+                        println!("{}", PrettyPrinted { expr: f.to_sexp() });
+                    }
+                }
+            }
+
+            for e in data.equipment {
+                if e.id.contains(&l.search_term) || e.names.matches(&terms) {
+                    did_write = true;
+                    println!("{}:", named(&e.id, &e.names, lang));
+                    if l.debug {
+                        println!("{:#?}", e);
+                    } else {
+                        println!("{}", PrettyPrinted { expr: e.to_sexp() });
+                    }
+                }
+            }
+
+            for f in data.food {
+                if f.id.contains(&l.search_term) || f.names.matches(&terms) {
                     did_write = true;
+                    println!("{}:", named(&f.id, &f.names, lang));
                     if l.debug {
                         println!("{:#?}", f);
                     } else {
@@ -72,8 +169,18 @@ fn main() {
         }
 
         SubCommand::Extract(e) => {
-            let save = read_save(&data, &e.save_name).unwrap();
-            let desc = save.description(e.summary);
+            let path = or_exit(choose_save(e.save.as_deref(), opts.save_dir.as_deref()));
+            let save = or_exit(load_save(&data, &path, e.lenient));
+            let mut desc = save.aquarium.description(e.summary, lang);
+            desc.elsewhere = save
+                .non_exhibit_animals
+                .iter()
+                .map(|(a, hosting)| ElsewhereDesc {
+                    animal: a.to_animal(),
+                    hosting: *hosting,
+                    name: a.species.names.get(lang).map(|n| n.to_string()),
+                })
+                .collect();
 
             if e.debug {
                 println!("{:#?}", desc);
@@ -83,7 +190,7 @@ fn main() {
         }
 
         SubCommand::Check(c) => {
-            fn do_work(c: Check, data: &GameData) -> util::Result<()> {
+            fn do_work(c: Check, data: &GameData, lang: &str) -> util::Result<()> {
                 let counts = make_species_counts(c.species);
                 let args = CheckArgs {
                     species: &counts,
@@ -92,11 +199,17 @@ fn main() {
                 };
                 let query = create_check_query(data, &args)?;
                 let result = check_for_viable_tank(&data, &query.animals);
-                print_exhibit_result(&query, &result);
+                print_exhibit_result(&query, &result, lang);
                 Ok(())
             }
 
-            match do_work(c, &data) {
+            let data = or_exit(restrict_for_planning(
+                data,
+                &selection,
+                opts.available_only.as_deref(),
+                opts.save_dir.as_deref(),
+            ));
+            match do_work(c, &data, lang) {
                 Ok(_) => (),
                 Err(error) => {
                     println!("{}", error);
@@ -105,40 +218,109 @@ fn main() {
             }
         }
 
-        SubCommand::List(list) => match list.kind {
-            ListOptions::Animals => {
-                println!("Animals:");
-                for x in data.species {
-                    println!("- {}", x.id);
+        SubCommand::List(list) => {
+            let data = or_exit(restrict_for_planning(
+                data,
+                &selection,
+                opts.available_only.as_deref(),
+                opts.save_dir.as_deref(),
+            ));
+            match list.kind {
+                ListOptions::Animals => {
+                    println!("Animals:");
+                    for x in data.species {
+                        println!("- {}", named(&x.id, &x.names, lang));
+                    }
                 }
-            }
-            ListOptions::Tanks => {
-                println!("Tanks:");
-                for x in data.tanks {
-                    println!("- {}", x.id);
+                ListOptions::Tanks => {
+                    println!("Tanks:");
+                    for x in data.tanks {
+                        println!("- {}", named(&x.id, &x.names, lang));
+                    }
                 }
-            }
-            ListOptions::Food => {
-                println!("Food:");
-                for x in data.food {
-                    println!("- {}", x);
+                ListOptions::Food => {
+                    println!("Food:");
+                    for x in data.food {
+                        let mut notes = Vec::new();
+                        if let Some(s) = &x.storage {
+                            notes.push(format!("stored {}", s));
+                        }
+                        if let Some(p) = &x.preparation {
+                            notes.push(format!("prepared at {}", p));
+                        }
+                        if let Some(c) = x.cost {
+                            notes.push(format!("costs {}", c));
+                        }
+                        if let Some(d) = x.dlc {
+                            notes.push(d.to_string());
+                        }
+
+                        let name = named(&x.id, &x.names, lang);
+                        if notes.is_empty() {
+                            println!("- {}", name);
+                        } else {
+                            println!("- {} ({})", name, notes.join(", "));
+                        }
+                    }
+                }
+                ListOptions::Mods => {
+                    println!("Mods (later ones override earlier ones):");
+                    for m in &mod_dirs {
+                        println!("- {}", m.display());
+                    }
+                }
+                ListOptions::Dlc => {
+                    println!("DLC:");
+                    for d in dlc::ALL_DLC {
+                        let status = if !installed_dlc.contains(d) {
+                            "not installed"
+                        } else if selection.allows(Some(*d)) {
+                            "enabled"
+                        } else {
+                            "disabled"
+                        };
+                        println!("- {} ({})", d, status);
+                    }
                 }
             }
-        },
+        }
 
         SubCommand::Validate(v) => {
-            fn do_work(v: Validate, data: &GameData) -> util::Result<()> {
+            fn do_work(v: Validate, data: &GameData, save_dir: Option<&Path>, lang: &str) -> util::Result<()> {
                 let options = RuleOptions {
                     assume_all_fish_fully_grown: v.assume_fully_grown,
                 };
-                let aquarium = load_aquarium_from_stdin()?.to_ref(data, &options)?;
+                if v.compare_game && v.save.is_none() && !v.latest {
+                    return Err(util::error("--compare-game needs a save, use --save or --latest"));
+                }
+
+                let (mut aquarium, states) = if v.save.is_some() || v.latest {
+                    // straight from the save, so violations name the uids the game uses
+                    let path = choose_save(v.save.as_deref(), save_dir)?;
+                    let save = load_save(data, &path, v.lenient)?;
+                    let mut aquarium = save.aquarium;
+                    if options.assume_all_fish_fully_grown {
+                        aquarium.assume_fully_grown();
+                    }
+                    (aquarium, save.animal_states)
+                } else {
+                    (load_aquarium_from_stdin()?.to_ref(data, &options)?, Default::default())
+                };
+                if let Some(floor) = v.floor {
+                    aquarium.exhibits.retain(|e| e.location.floor == Some(floor));
+                }
                 let args = ValidateArgs { aquarium: &aquarium };
                 let result = validate_aquarium(data, &args);
-                print_aquarium_result(&result, v.debug);
+                let name = species_namer(data, lang);
+                if v.compare_game {
+                    print_game_comparison(&compare::compare_with_game(&aquarium, &states, &result), &name);
+                } else {
+                    print_aquarium_result(&result, v.debug, &name, &aquarium.numbered_animals);
+                }
                 Ok(())
             }
 
-            match do_work(v, &data) {
+            match do_work(v, &data, opts.save_dir.as_deref(), lang) {
                 Ok(_) => (),
                 Err(error) => {
                     println!("{}", error);
@@ -148,7 +330,7 @@ fn main() {
         }
 
         SubCommand::Expand(e) => {
-            fn do_work(e: Expand, data: &GameData) -> util::Result<()> {
+            fn do_work(e: Expand, data: &GameData, lang: &str) -> util::Result<()> {
                 let options = RuleOptions {
                     assume_all_fish_fully_grown: false,
                 };
@@ -168,7 +350,7 @@ fn main() {
                 let base_result = check_for_viable_tank(&data, &query.animals);
 
                 if !base_result.is_okay() {
-                    print_exhibit_result(&query, &base_result);
+                    print_exhibit_result(&query, &base_result, lang);
                     return Ok(());
                 }
 
@@ -196,7 +378,7 @@ fn main() {
                     }
 
                     if do_print && exhibit.animals.len() > 0 {
-                        print_violations(&expand_result.violations);
+                        print_violations(&expand_result.violations, &species_namer(data, lang));
                         let original_environment = environment_for_exhibit(exhibit);
                         print_environment_differences(&original_environment, &expand_result.minimum_viable_environment);
                     }
@@ -209,7 +391,49 @@ fn main() {
                 Ok(())
             }
 
-            match do_work(e, &data) {
+            let data = or_exit(restrict_for_planning(
+                data,
+                &selection,
+                opts.available_only.as_deref(),
+                opts.save_dir.as_deref(),
+            ));
+            match do_work(e, &data, lang) {
+                Ok(_) => (),
+                Err(error) => {
+                    println!("{}", error);
+                    std::process::exit(2);
+                }
+            }
+        }
+
+        SubCommand::Lifecycle(l) => {
+            fn do_work(l: Lifecycle, data: &GameData, lang: &str) -> util::Result<()> {
+                let adult = lookup(data, &l.species)?;
+                let stages = lifecycle::life_cycle(data, adult)?;
+                print_life_cycle(adult, &stages, lang);
+                Ok(())
+            }
+
+            match do_work(l, &data, lang) {
+                Ok(_) => (),
+                Err(error) => {
+                    println!("{}", error);
+                    std::process::exit(2);
+                }
+            }
+        }
+
+        SubCommand::Watch(w) => or_exit(run_watch(&w, &data, opts.save_dir.as_deref())),
+
+        SubCommand::DiffSaves(d) => {
+            fn do_work(d: DiffSaves, data: &GameData, save_dir: Option<&Path>) -> util::Result<()> {
+                let old = load_save(data, &paths::find_save(&d.old, save_dir)?, d.lenient)?;
+                let new = load_save(data, &paths::find_save(&d.new, save_dir)?, d.lenient)?;
+                print_save_diff(&save_diff::diff_saves(&old.aquarium, &new.aquarium));
+                Ok(())
+            }
+
+            match do_work(d, &data, opts.save_dir.as_deref()) {
                 Ok(_) => (),
                 Err(error) => {
                     println!("{}", error);
@@ -217,6 +441,190 @@ fn main() {
                 }
             }
         }
+
+        SubCommand::Park(p) => {
+            let path = or_exit(choose_save(p.save.as_deref(), opts.save_dir.as_deref()));
+            let save = or_exit(load_save(&data, &path, p.lenient));
+            let result = validate_aquarium(&data, &ValidateArgs { aquarium: &save.aquarium });
+            print_park(&save.park, &park::feeding_coverage(&save.park, &result));
+        }
+
+        SubCommand::Saves(s) => match s.action {
+            SavesAction::List => {
+                let save_dir = or_exit(paths::find_save_dir(opts.save_dir.as_deref()));
+                let saves = or_exit(paths::list_saves(&save_dir));
+                let exhibits: Vec<_> = saves.iter().map(|s| count_exhibits(&s.path)).collect();
+                print_saves(&save_dir, &saves, &exhibits);
+            }
+        },
+
+        SubCommand::Cache(_) | SubCommand::DataDiff(_) | SubCommand::AuditData(_) => unreachable!(),
+    }
+}
+
+fn run_cache_command(c: &Cache, opts: &Opts) {
+    let path = or_exit(cache::cache_path());
+
+    match c.action {
+        CacheAction::Status => {
+            println!("Cache file: {}", path.display());
+            let found = paths::find_data_dir(opts.data_dir.as_deref())
+                .and_then(|dir| Ok((paths::find_mod_dirs(&dir, &opts.mod_dir, !opts.no_mods)?, dir)));
+            match found {
+                Ok((mods, dir)) => {
+                    println!("Game data: {}", dir.display());
+                    for m in &mods {
+                        println!("Mod: {}", m.display());
+                    }
+                    println!("Status: {}", cache::status(&path, &cache::cache_key(&dir, &mods)));
+                }
+                Err(error) => println!("Status: unknown, {}", error),
+            }
+            if let Ok(meta) = std::fs::metadata(&path) {
+                println!("Size: {} bytes", meta.len());
+            }
+        }
+        CacheAction::Clear => {
+            if or_exit(cache::clear(&path)) {
+                println!("Removed {}", path.display());
+            } else {
+                println!("No cache at {}", path.display());
+            }
+        }
+    }
+}
+
+fn run_data_diff(d: &DataDiff, opts: &Opts) -> util::Result<()> {
+    // mods are left out on purpose, this is about what the game itself changed
+    let selection = DlcSelection {
+        only: opts.dlc.clone(),
+        excluded: opts.no_dlc.clone(),
+    };
+    let old = read_game_data(&d.old_dir, &[])?.restrict_to(&selection);
+    let new = read_game_data(&d.new_dir, &[])?.restrict_to(&selection);
+
+    print_data_diff(&data_diff::diff_game_data(&old, &new));
+
+    if let Some(path) = &d.aquarium {
+        let file = std::fs::File::open(path).map_err(|e| util::error(format!("cannot open {}: {}", path.display(), e)))?;
+        let aquarium: AquariumDesc = from_reader(file)?;
+        let options = RuleOptions {
+            assume_all_fish_fully_grown: d.assume_fully_grown,
+        };
+
+        println!();
+        print_verdict_changes(&data_diff::verdict_changes(&old, &new, &aquarium, &options));
+    }
+
+    Ok(())
+}
+
+/// Validates the watched save (or the most recent one) once, then again whenever a save in its
+/// directory is written, until interrupted.
+fn run_watch(w: &Watch, data: &GameData, save_dir: Option<&Path>) -> util::Result<()> {
+    use notify::{EventKind, RecursiveMode, Watcher};
+
+    let target = match &w.save {
+        Some(s) => Some(std::fs::canonicalize(paths::find_save(s, save_dir)?)?),
+        None => None,
+    };
+    let dir = match &target {
+        Some(t) => t.parent().ok_or(util::error("save has no directory"))?.to_path_buf(),
+        None => std::fs::canonicalize(paths::find_save_dir(save_dir)?)?,
+    };
+    let options = RuleOptions {
+        assume_all_fish_fully_grown: w.assume_fully_grown,
+    };
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+
+    let mut previous: std::collections::HashMap<PathBuf, watch::Problems> = std::collections::HashMap::new();
+    let mut revalidate = |path: &Path| {
+        let mut aquarium = match load_save(data, path, w.lenient) {
+            Ok(save) => save.aquarium,
+            // most likely caught the game halfway through writing it, the next event will retry
+            Err(e) => {
+                eprintln!("warning: cannot read {}: {}", path.display(), e);
+                return;
+            }
+        };
+        if options.assume_all_fish_fully_grown {
+            aquarium.assume_fully_grown();
+        }
+
+        let result = validate_aquarium(data, &ValidateArgs { aquarium: &aquarium });
+        let problems = watch::problems(&aquarium, &result);
+        let old = previous.remove(path);
+        let changes = watch::problem_changes(old.as_ref().unwrap_or(&watch::Problems::new()), &problems);
+        print_problem_changes(path, &changes, old.is_none());
+        previous.insert(path.to_path_buf(), problems);
+    };
+
+    println!("Watching {}", target.as_deref().unwrap_or(&dir).display());
+    if let Some(path) = target.clone().or_else(|| paths::latest_save(Some(&dir)).ok()) {
+        revalidate(&path);
+    }
+
+    while let Ok(event) = receiver.recv() {
+        // the game writes a save in several steps, let it finish and handle them all at once
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        let mut changed: Vec<PathBuf> = Vec::new();
+
+        for event in std::iter::once(event).chain(receiver.try_iter()) {
+            let event = match event {
+                Ok(event) => event,
+                Err(e) => {
+                    eprintln!("warning: error watching {}: {}", dir.display(), e);
+                    continue;
+                }
+            };
+            if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                continue;
+            }
+            for path in event.paths {
+                let is_save = match &target {
+                    Some(t) => path == *t,
+                    None => path.extension().is_some_and(|e| e == "sav"),
+                };
+                if is_save && !changed.contains(&path) {
+                    changed.push(path);
+                }
+            }
+        }
+
+        for path in changed {
+            revalidate(&path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Limits the game data to the selected DLC and, given a save, to what its player has unlocked. Only
+/// for the commands that pick content: a save holds whatever its park has, so reading one needs all
+/// of it.
+fn restrict_for_planning(
+    data: GameData,
+    selection: &DlcSelection,
+    available_only: Option<&str>,
+    save_dir: Option<&Path>,
+) -> util::Result<GameData> {
+    let data = data.restrict_to(selection);
+    Ok(match available_only {
+        Some(save) => data.restrict_to_unlocked(&read_progress(&paths::find_save(save, save_dir)?)?),
+        None => data,
+    })
+}
+
+fn or_exit<T>(result: util::Result<T>) -> T {
+    match result {
+        Ok(x) => x,
+        Err(error) => {
+            println!("{}", error);
+            std::process::exit(2);
+        }
     }
 }
 
@@ -224,6 +632,25 @@ fn make_species_counts(counts: Vec<(String, u16)>) -> Vec<SpeciesCount> {
     counts.into_iter().map(|(species, count)| SpeciesCount { species, count }).collect()
 }
 
+/// Reads a save, leniently if asked, warning about whatever was left out.
+fn load_save<'a>(data: &'a GameData, path: &Path, lenient: bool) -> util::Result<Save<'a>> {
+    let save = if lenient {
+        read_save_lenient(data, path)?
+    } else {
+        read_save(data, path)?
+    };
+    print_save_warnings(&save.diagnostics);
+    Ok(save)
+}
+
+/// The save named on the command line, or the most recent one if there isn't one (`--latest`).
+fn choose_save(save: Option<&str>, save_dir: Option<&Path>) -> util::Result<PathBuf> {
+    match save {
+        Some(s) => paths::find_save(s, save_dir),
+        None => paths::latest_save(save_dir),
+    }
+}
+
 fn load_aquarium_from_stdin() -> util::Result<AquariumDesc> {
     let stdin = std::io::stdin();
     from_reader::<std::io::Stdin, AquariumDesc>(stdin)
@@ -234,6 +661,36 @@ fn load_aquarium_from_stdin() -> util::Result<AquariumDesc> {
 struct Opts {
     #[clap(subcommand)]
     command: SubCommand,
+    /// Game data directory (the one containing `Data/`), overrides MEGAQUARIUM_DATA_DIR and discovery
+    #[clap(long, global = true)]
+    data_dir: Option<PathBuf>,
+    /// Save directory, overrides MEGAQUARIUM_SAVE_DIR and discovery
+    #[clap(long, global = true)]
+    save_dir: Option<PathBuf>,
+    /// Always parse the game data files instead of using the cached snapshot
+    #[clap(long, global = true)]
+    no_cache: bool,
+    /// Only consider content from these DLC (base game content is always included), e.g. `--dlc freshwater-frenzy`;
+    /// saves are always read with all of it
+    #[clap(long, global = true, value_delimiter = ',', value_parser = parse_dlc)]
+    dlc: Vec<dlc::Dlc>,
+    /// Ignore content from these DLC, except when reading saves
+    #[clap(long, global = true, value_delimiter = ',', value_parser = parse_dlc)]
+    no_dlc: Vec<dlc::Dlc>,
+    /// Also load the mod in this directory; can be given several times, later ones override
+    /// earlier ones
+    #[clap(long, global = true)]
+    mod_dir: Vec<PathBuf>,
+    /// Don't load Steam Workshop mods or those listed in MEGAQUARIUM_MOD_DIRS
+    #[clap(long, global = true)]
+    no_mods: bool,
+    /// Only consider species, tanks and fixtures the player has unlocked in this save (a name in
+    /// the save directory or a path) when looking up, listing or planning
+    #[clap(long, global = true, value_name = "SAVE")]
+    available_only: Option<String>,
+    /// Show names in this language (a string file name like `en`) as well as ids
+    #[clap(long, global = true)]
+    lang: Option<String>,
 }
 
 #[derive(Parser)]
@@ -244,6 +701,14 @@ enum SubCommand {
     List(List),
     Validate(Validate),
     Expand(Expand),
+    Lifecycle(Lifecycle),
+    Watch(Watch),
+    DiffSaves(DiffSaves),
+    Park(Park),
+    Saves(Saves),
+    Cache(Cache),
+    DataDiff(DataDiff),
+    AuditData(AuditData),
 }
 
 /// Show information about the any game entity for a given search string.
@@ -255,10 +720,18 @@ struct Lookup {
     debug: bool,
 }
 
-/// Print an aquarium summary in s-expression format to stdout for a given save filename
+/// Print an aquarium summary in s-expression format to stdout for a given save
 #[derive(Debug, Parser)]
 struct Extract {
-    save_name: String,
+    /// The name of a save in the save directory, or the path to any save file
+    #[clap(required_unless_present = "latest")]
+    save: Option<String>,
+    /// Use the most recently modified save in the save directory
+    #[clap(long, conflicts_with = "save")]
+    latest: bool,
+    /// Leave out whatever in the save can't be read, with a warning, instead of failing
+    #[clap(long)]
+    lenient: bool,
     /// Extract a summary of animals instead of individuals, will not have age.
     #[clap(short)]
     summary: bool,
@@ -281,9 +754,26 @@ struct Check {
     assume_fully_grown: bool,
 }
 
-/// Validates an aquarium provided over stdin
+/// Validates an aquarium provided over stdin, or read from a save
 #[derive(Debug, Parser)]
 struct Validate {
+    /// Validate this save (a name in the save directory or a path) instead of reading stdin
+    #[clap(long)]
+    save: Option<String>,
+    /// Validate the most recently modified save in the save directory
+    #[clap(long, conflicts_with = "save")]
+    latest: bool,
+    /// Leave out whatever in the save can't be read, with a warning, and validate the exhibits
+    /// that could be read completely
+    #[clap(long)]
+    lenient: bool,
+    /// Only validate the exhibits on this floor
+    #[clap(long)]
+    floor: Option<u8>,
+    /// Show the game's own view of each animal (health, happiness, unmet needs) next to our
+    /// violations; needs --save or --latest
+    #[clap(long)]
+    compare_game: bool,
     /// Show debug-printed structs instead of pretty output
     #[clap(short)]
     debug: bool,
@@ -307,11 +797,43 @@ struct Expand {
     assume_fully_grown: bool,
 }
 
+/// Show every stage of a species' life cycle, from egg or fry to adult, with its size, duration and needs
+#[derive(Debug, Parser)]
+struct Lifecycle {
+    /// Search string for the adult species
+    species: String,
+}
+
+/// Report what changed between two versions of the game data, e.g. before and after a patch
+#[derive(Debug, Parser)]
+struct DataDiff {
+    /// Game data directory of the old version
+    old_dir: PathBuf,
+    /// Game data directory of the new version
+    new_dir: PathBuf,
+    /// Also validate this aquarium file under both versions and report exhibits whose verdict changed
+    #[clap(long)]
+    aquarium: Option<PathBuf>,
+    /// Consider all fish fully grown for the purposes of predation
+    #[clap(long, short = 'g')]
+    assume_fully_grown: bool,
+}
+
+/// Report keys in the game data that the loaders don't understand, e.g. a new animal trait added by a patch
+#[derive(Debug, Parser)]
+struct AuditData {
+    /// List every object with an unknown key instead of a few examples
+    #[clap(short)]
+    all: bool,
+}
+
 #[derive(Debug, Copy, Clone, ValueEnum)]
 enum ListOptions {
     Animals,
     Tanks,
     Food,
+    Dlc,
+    Mods,
 }
 
 /// List various game objects
@@ -320,6 +842,77 @@ struct List {
     kind: ListOptions,
 }
 
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum CacheAction {
+    Status,
+    Clear,
+}
+
+/// Re-validate a save every time the game writes it, printing the problems that appear or go away
+#[derive(Debug, Parser)]
+struct Watch {
+    /// The save to watch (a name in the save directory or a path); without one, whichever save in
+    /// the save directory is written
+    save: Option<String>,
+    /// Consider all fish fully grown for the purposes of predation
+    #[clap(long, short = 'g')]
+    assume_fully_grown: bool,
+    /// Leave out whatever in the save can't be read, with a warning, and watch the exhibits that
+    /// could be read completely
+    #[clap(long)]
+    lenient: bool,
+}
+
+/// Compare two saves of the same park, showing what happened to each tank, animal and fixture
+#[derive(Debug, Parser)]
+struct DiffSaves {
+    /// The earlier save (a name in the save directory or a path)
+    old: String,
+    /// The later save
+    new: String,
+    /// Leave out whatever in either save can't be read, with a warning, instead of failing
+    #[clap(long)]
+    lenient: bool,
+}
+
+/// Show a save's finances, guests and staff, and whether the staff can prepare every exhibit's food
+#[derive(Debug, Parser)]
+struct Park {
+    /// The name of a save in the save directory, or the path to any save file
+    #[clap(required_unless_present = "latest")]
+    save: Option<String>,
+    /// Use the most recently modified save in the save directory
+    #[clap(long, conflicts_with = "save")]
+    latest: bool,
+    /// Leave out whatever in the save can't be read, with a warning, instead of failing
+    #[clap(long)]
+    lenient: bool,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum SavesAction {
+    List,
+}
+
+/// Show the saves in the save directory, most recent first
+#[derive(Debug, Parser)]
+struct Saves {
+    action: SavesAction,
+}
+
+/// Inspect or remove the cached snapshot of the parsed game data
+#[derive(Debug, Parser)]
+struct Cache {
+    action: CacheAction,
+}
+
+fn parse_dlc(s: &str) -> Result<dlc::Dlc, String> {
+    dlc::Dlc::from_str(s).ok_or_else(|| {
+        let names: Vec<_> = dlc::ALL_DLC.iter().map(|d| d.as_str()).collect();
+        format!("unknown DLC `{}`, expected one of: {}", s, names.join(", "))
+    })
+}
+
 fn parse_key_val<T, U>(s: &str) -> Result<(T, U), Box<dyn Error + Send + Sync + 'static>>
 where
     T: std::str::FromStr,
@@ -0,0 +1,58 @@
+// pattern: Functional Core
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The language shown when none is asked for.
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// What the game calls something in each language, keyed by language code (e.g. `en`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Names(pub BTreeMap<String, String>);
+
+impl Names {
+    pub fn get(&self, lang: &str) -> Option<&str> {
+        self.0.get(lang).map(|n| n.as_str())
+    }
+
+    /// Whether a name in any language contains every part, ignoring case.
+    pub fn matches(&self, parts: &[&str]) -> bool {
+        self.0.values().any(|name| {
+            let name = name.to_lowercase();
+            parts.iter().all(|p| name.contains(&p.to_lowercase()))
+        })
+    }
+
+    pub fn languages(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(|l| l.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn names() -> Names {
+        Names(BTreeMap::from([
+            ("en".to_string(), "Arabian Butterflyfish".to_string()),
+            ("de".to_string(), "Arabischer Falterfisch".to_string()),
+        ]))
+    }
+
+    #[test]
+    fn test_get_by_language() {
+        let names = names();
+        assert_eq!(names.get("de"), Some("Arabischer Falterfisch"));
+        assert_eq!(names.get("fr"), None);
+        assert_eq!(names.languages().collect::<Vec<_>>(), vec!["de", "en"]);
+    }
+
+    #[test]
+    fn test_matches_any_language_ignoring_case() {
+        let names = names();
+        assert!(names.matches(&["arabian", "butterflyfish"]));
+        assert!(names.matches(&["Falterfisch"]));
+        assert!(!names.matches(&["arabian", "falterfisch"]));
+        assert!(!Names::default().matches(&["arabian"]));
+    }
+}
@@ -0,0 +1,148 @@
+// pattern: Functional Core
+
+use crate::check::AquariumCheckResult;
+
+/// Everything in a save that isn't part of an exhibit.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParkState {
+    /// None if the save doesn't record it
+    pub money: Option<i64>,
+    pub prestige: Option<u32>,
+    /// guests currently in the park
+    pub guests: u32,
+    pub staff: Vec<StaffMember>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaffMember {
+    pub id: u64,
+    /// what the player named them, if anything
+    pub name: Option<String>,
+    /// the kind of staff, e.g. `aquarist`
+    pub role: String,
+    pub feeding: u8,
+    pub maintenance: u8,
+}
+
+impl StaffMember {
+    pub fn label(&self) -> String {
+        match &self.name {
+            Some(n) => format!("{} ({} {})", n, self.role, self.id),
+            None => format!("{} {}", self.role, self.id),
+        }
+    }
+}
+
+impl ParkState {
+    /// The staff member with the highest feeding skill, the first one hired if there's a tie.
+    pub fn best_feeder(&self) -> Option<&StaffMember> {
+        self.staff.iter().rev().max_by_key(|s| s.feeding)
+    }
+}
+
+/// How well the staff can feed one exhibit.
+#[derive(Debug, PartialEq)]
+pub struct FeedingCoverage {
+    pub exhibit: String,
+    /// the food that needs the most skill
+    pub food: String,
+    pub needed: u8,
+    /// the highest feeding skill among the staff, 0 without any staff
+    pub available: u8,
+}
+
+impl FeedingCoverage {
+    pub fn is_covered(&self) -> bool {
+        self.available >= self.needed
+    }
+}
+
+/// For every exhibit with food that takes skill to prepare, whether someone on the staff has it.
+pub fn feeding_coverage(park: &ParkState, result: &AquariumCheckResult) -> Vec<FeedingCoverage> {
+    let available = park.best_feeder().map(|s| s.feeding).unwrap_or(0);
+
+    result
+        .exhibits
+        .iter()
+        .filter_map(|e| {
+            let hardest = e.food.iter().filter(|f| f.skill > 0).max_by_key(|f| f.skill)?;
+            Some(FeedingCoverage {
+                exhibit: e.name.clone(),
+                food: hardest.food.clone(),
+                needed: hardest.skill,
+                available,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::check::{ExhibitValidation, FoodAmount};
+    use crate::economy::ExhibitEconomy;
+    use crate::equipment::LifeSupport;
+    use crate::tank::{test::test_environment, LoadedEnvironment};
+
+    fn staff(id: u64, feeding: u8) -> StaffMember {
+        StaffMember {
+            id,
+            name: None,
+            role: "aquarist".to_string(),
+            feeding,
+            maintenance: 0,
+        }
+    }
+
+    fn food(food: &str, skill: u8) -> FoodAmount {
+        FoodAmount {
+            food: food.to_string(),
+            count: 1,
+            skill,
+            storage: None,
+            preparation: None,
+            cost: None,
+        }
+    }
+
+    fn exhibit(name: &str, food: Vec<FoodAmount>) -> ExhibitValidation {
+        ExhibitValidation {
+            name: name.to_string(),
+            loaded_environment: LoadedEnvironment::default(),
+            life_support: LifeSupport::default(),
+            water: None,
+            minimum_viable_environment: test_environment(),
+            food,
+            economy: ExhibitEconomy::default(),
+            violations: vec![],
+            location: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_feeding_coverage() {
+        let park = ParkState {
+            staff: vec![staff(1, 1), staff(2, 2), staff(3, 2)],
+            ..ParkState::default()
+        };
+        let result = AquariumCheckResult {
+            exhibits: vec![
+                exhibit("Reef", vec![food("flakes", 0), food("krill", 2)]),
+                exhibit("Shark", vec![food("fish", 3), food("krill", 2)]),
+                exhibit("Pond", vec![food("flakes", 0)]),
+            ],
+        };
+
+        let coverage = feeding_coverage(&park, &result);
+
+        assert_eq!(park.best_feeder().unwrap().id, 2);
+        assert_eq!(coverage.len(), 2);
+        assert!(coverage[0].is_covered());
+        assert_eq!(
+            (coverage[1].food.as_str(), coverage[1].needed, coverage[1].available),
+            ("fish", 3, 2)
+        );
+        assert!(!coverage[1].is_covered());
+        assert_eq!(feeding_coverage(&ParkState::default(), &result)[0].available, 0);
+    }
+}
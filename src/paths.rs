@@ -2,7 +2,22 @@
 
 use directories::UserDirs;
 use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::dlc::{Dlc, ALL_DLC};
+use crate::util::{error, Result};
+
+pub const DATA_DIR_VAR: &str = "MEGAQUARIUM_DATA_DIR";
+pub const SAVE_DIR_VAR: &str = "MEGAQUARIUM_SAVE_DIR";
+/// A list of extra mod directories, separated like PATH.
+pub const MOD_DIRS_VAR: &str = "MEGAQUARIUM_MOD_DIRS";
+
+/// Steam's app id for Megaquarium, used to find the Proton prefix.
+const STEAM_APP_ID: &str = "600480";
 
 static POSSIBLE_DATA_DIRECTORIES: &'static [&str] = &[
     "C:/Program Files (x86)/Steam/steamapps/common/Megaquarium/Megaquarium_Data/GameData",
@@ -10,54 +25,524 @@ static POSSIBLE_DATA_DIRECTORIES: &'static [&str] = &[
     "~/Library/Application Support/Steam/steamapps/common/Megaquarium/Megaquarium.app/Contents/GameData",
 ];
 
-pub const TANK_PATHS: &[&str] = &["Data/tanks.data", "DLC/Freshwater Frenzy/Data/ff tanks.data"];
-pub const FISH_PATHS: &[&str] = &[
-    "Data/animals.data",
-    "Data/corals.data",
-    "DLC/Freshwater Frenzy/Data/ff animals.data",
-];
-pub const FIXTURE_PATHS: &[&str] = &[
-    "Data/scenery.data",
-    "Data/equipment.data",
-    "DLC/Freshwater Frenzy/Data/ff scenery.data",
-    "DLC/Freshwater Frenzy/Data/ff equipment.data",
-];
-pub const FOOD_PATHS: &[&str] = &[
-    "Data/fishFood.data",
-    "DLC/Freshwater Frenzy/Data/ff fishFood.data",
-    "DLC/Architect's Collection/Data/ac fishFood.data",
+/// Places a Linux Steam install may keep its root library (native, flatpak, snap).
+static LINUX_STEAM_ROOTS: &[&str] = &[
+    "~/.steam/steam",
+    "~/.local/share/Steam",
+    "~/.var/app/com.valvesoftware.Steam/.local/share/Steam",
+    "~/snap/steam/common/.local/share/Steam",
 ];
 
-pub fn find_data_dir() -> PathBuf {
-    let dirs = UserDirs::new().unwrap();
-    let home = dirs.home_dir();
+const GAME_DATA_IN_LIBRARY: &str = "steamapps/common/Megaquarium/Megaquarium_Data/GameData";
+const SAVES_IN_DOCUMENTS: &str = "My Games/Megaquarium/Saves";
+const MOD_DATA_EXTENSION: &str = "data";
+const SAVE_EXTENSION: &str = "sav";
+const LANGUAGES_DIR: &str = "Languages";
+const LANGUAGE_EXTENSION: &str = "lang";
+
+/// The data files that make up one content source (the base game or a DLC), relative to the game
+/// data directory.
+pub struct DataFiles {
+    pub tanks: &'static [&'static str],
+    pub animals: &'static [&'static str],
+    pub fixtures: &'static [&'static str],
+    pub food: &'static [&'static str],
+}
+
+pub const BASE_GAME_FILES: DataFiles = DataFiles {
+    tanks: &["Data/tanks.data"],
+    animals: &["Data/animals.data", "Data/corals.data"],
+    fixtures: &["Data/scenery.data", "Data/equipment.data"],
+    food: &["Data/fishFood.data"],
+};
+
+const FRESHWATER_FRENZY_FILES: DataFiles = DataFiles {
+    tanks: &["DLC/Freshwater Frenzy/Data/ff tanks.data"],
+    animals: &["DLC/Freshwater Frenzy/Data/ff animals.data"],
+    fixtures: &[
+        "DLC/Freshwater Frenzy/Data/ff scenery.data",
+        "DLC/Freshwater Frenzy/Data/ff equipment.data",
+    ],
+    food: &["DLC/Freshwater Frenzy/Data/ff fishFood.data"],
+};
+
+const ARCHITECTS_COLLECTION_FILES: DataFiles = DataFiles {
+    tanks: &[],
+    animals: &[],
+    fixtures: &[],
+    food: &["DLC/Architect's Collection/Data/ac fishFood.data"],
+};
+
+pub fn dlc_files(dlc: Dlc) -> &'static DataFiles {
+    match dlc {
+        Dlc::FreshwaterFrenzy => &FRESHWATER_FRENZY_FILES,
+        Dlc::ArchitectsCollection => &ARCHITECTS_COLLECTION_FILES,
+    }
+}
+
+/// The DLC whose directories exist in the given game data directory.
+pub fn installed_dlc(directory: &Path) -> Vec<Dlc> {
+    ALL_DLC.iter().copied().filter(|d| directory.join(d.directory()).is_dir()).collect()
+}
+
+impl DataFiles {
+    pub fn all(&self) -> impl Iterator<Item = &'static str> {
+        self.tanks.iter().chain(self.animals).chain(self.fixtures).chain(self.food).copied()
+    }
+}
+
+/// Every game data file we might read, relative to the data directory, whether or not the DLC it
+/// belongs to is installed.
+pub fn game_data_files() -> impl Iterator<Item = &'static str> {
+    BASE_GAME_FILES.all().chain(ALL_DLC.iter().flat_map(|d| dlc_files(*d).all()))
+}
 
-    for d in POSSIBLE_DATA_DIRECTORIES {
-        let directory = d.replace("~", &home.to_str().unwrap());
+/// The string files of the base game and each installed DLC, relative to the data directory, with
+/// the language each is for. They live in `Languages/` and are named after the language code, e.g.
+/// `Languages/en.lang`. The base game's come first so a DLC's strings can override them.
+pub fn language_files(directory: &Path) -> Vec<(String, String)> {
+    let roots = std::iter::once(String::new()).chain(installed_dlc(directory).into_iter().map(|d| format!("{}/", d.directory())));
+    let mut result = Vec::new();
 
-        let potential = Path::new(&directory);
-        if potential.exists() {
-            return potential.to_path_buf();
+    for root in roots {
+        let dir = format!("{}{}", root, LANGUAGES_DIR);
+        let entries = match fs::read_dir(directory.join(&dir)) {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+        let mut files = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|e| e == LANGUAGE_EXTENSION) {
+                if let Some(lang) = path.file_stem().and_then(|s| s.to_str()) {
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    files.push((lang.to_string(), format!("{}/{}", dir, name)));
+                }
+            }
         }
+        files.sort();
+        result.extend(files);
     }
 
-    panic!("Cannot find data directory");
+    result
 }
 
-pub fn find_save_dir() -> PathBuf {
-    let dirs = UserDirs::new().unwrap();
+#[derive(Debug)]
+pub struct DirectoryNotFound {
+    pub what: &'static str,
+    pub flag: &'static str,
+    pub variable: &'static str,
+    pub probed: Vec<PathBuf>,
+}
 
-    match env::consts::OS {
-        "windows" => {
-            let docs = dirs.document_dir().unwrap();
-            docs.join("My Games/Megaquarium/Saves")
+impl fmt::Display for DirectoryNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Cannot find {} directory, tried:", self.what)?;
+        for p in &self.probed {
+            writeln!(f, "- {}", p.display())?;
+        }
+        write!(f, "Use {} or set {} to specify it", self.flag, self.variable)
+    }
+}
+
+impl Error for DirectoryNotFound {}
+
+/// Resolves the game data directory: an explicit path wins, then the environment variable, then
+/// the usual Steam install locations for the current OS.
+pub fn find_data_dir(explicit: Option<&Path>) -> Result<PathBuf> {
+    let candidates = match override_dir(explicit, DATA_DIR_VAR) {
+        Some(p) => vec![p],
+        None => data_dir_candidates(),
+    };
+
+    match first_existing(candidates) {
+        Ok(p) => Ok(p),
+        Err(probed) => Err(Box::new(DirectoryNotFound {
+            what: "game data",
+            flag: "--data-dir",
+            variable: DATA_DIR_VAR,
+            probed,
+        })),
+    }
+}
+
+/// Resolves the save directory with the same precedence as `find_data_dir`.
+pub fn find_save_dir(explicit: Option<&Path>) -> Result<PathBuf> {
+    let candidates = match override_dir(explicit, SAVE_DIR_VAR) {
+        Some(p) => vec![p],
+        None => save_dir_candidates(),
+    };
+
+    match first_existing(candidates) {
+        Ok(p) => Ok(p),
+        Err(probed) => Err(Box::new(DirectoryNotFound {
+            what: "save",
+            flag: "--save-dir",
+            variable: SAVE_DIR_VAR,
+            probed,
+        })),
+    }
+}
+
+/// A save given on the command line: anything that looks like a path (it has a directory or the
+/// `.sav` extension) is used as is, anything else is the name of a save in the save directory.
+pub fn find_save(save: &str, save_dir: Option<&Path>) -> Result<PathBuf> {
+    let path = Path::new(save);
+    let is_path = path.components().count() > 1 || path.extension().is_some_and(|e| e == SAVE_EXTENSION);
+
+    if is_path {
+        Ok(path.to_path_buf())
+    } else {
+        Ok(find_save_dir(save_dir)?.join(format!("{}.{}", save, SAVE_EXTENSION)))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SaveFile {
+    /// what to pass on the command line to use this save
+    pub name: String,
+    pub path: PathBuf,
+    pub modified: Option<SystemTime>,
+}
+
+/// The saves in a save directory, most recently modified first.
+pub fn list_saves(save_dir: &Path) -> Result<Vec<SaveFile>> {
+    let mut result = Vec::new();
+
+    for entry in fs::read_dir(save_dir)?.flatten() {
+        let path = entry.path();
+        if !path.is_file() || path.extension().is_none_or(|e| e != SAVE_EXTENSION) {
+            continue;
+        }
+        if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+            result.push(SaveFile {
+                name: name.to_string(),
+                modified: entry.metadata().and_then(|m| m.modified()).ok(),
+                path,
+            });
+        }
+    }
+
+    result.sort_by(|a, b| b.modified.cmp(&a.modified).then_with(|| a.name.cmp(&b.name)));
+    Ok(result)
+}
+
+/// The most recently modified save in the save directory.
+pub fn latest_save(save_dir: Option<&Path>) -> Result<PathBuf> {
+    let dir = find_save_dir(save_dir)?;
+    match list_saves(&dir)?.into_iter().next() {
+        Some(save) => Ok(save.path),
+        None => Err(error(format!("No saves in {}", dir.display()))),
+    }
+}
+
+/// The mod directories to layer over the game data, in the order they apply (later ones win):
+/// Steam Workshop subscriptions of the library the game is installed in, then the directories
+/// listed in the environment variable, then explicit ones. `discover` controls the first two.
+pub fn find_mod_dirs(data_dir: &Path, explicit: &[PathBuf], discover: bool) -> Result<Vec<PathBuf>> {
+    let mut result = Vec::new();
+
+    if discover {
+        if let Some(workshop) = workshop_dir(data_dir) {
+            result.extend(subdirectories(&workshop));
+        }
+
+        if let Some(list) = env::var_os(MOD_DIRS_VAR) {
+            result.extend(env::split_paths(&list).filter(|p| !p.as_os_str().is_empty()));
+        }
+    }
+
+    result.extend(explicit.iter().cloned());
+
+    for dir in &result {
+        if !dir.is_dir() {
+            return Err(Box::new(DirectoryNotFound {
+                what: "mod",
+                flag: "--mod-dir",
+                variable: MOD_DIRS_VAR,
+                probed: vec![dir.clone()],
+            }));
         }
-        "macos" => {
-            let home = dirs.home_dir();
-            home.join("Library/Application Support/Megaquarium/Saves")
+    }
+
+    Ok(result)
+}
+
+/// Every `.data` file anywhere inside a mod directory, sorted so mods load deterministically.
+pub fn mod_data_files(mod_dir: &Path) -> Vec<PathBuf> {
+    let mut result = Vec::new();
+    let mut pending = vec![mod_dir.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else if path.extension().is_some_and(|e| e == MOD_DATA_EXTENSION) {
+                result.push(path);
+            }
         }
+    }
+
+    result.sort();
+    result
+}
+
+/// The workshop content directory of the Steam library containing the game data directory.
+fn workshop_dir(data_dir: &Path) -> Option<PathBuf> {
+    let steamapps = data_dir.ancestors().find(|a| a.file_name().is_some_and(|n| n == "steamapps"))?;
+    let dir = steamapps.join("workshop/content").join(STEAM_APP_ID);
+    dir.is_dir().then_some(dir)
+}
+
+fn subdirectories(dir: &Path) -> Vec<PathBuf> {
+    let mut result: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(entries) => entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()).collect(),
+        Err(_) => Vec::new(),
+    };
+    result.sort();
+    result
+}
+
+fn override_dir(explicit: Option<&Path>, variable: &str) -> Option<PathBuf> {
+    match explicit {
+        Some(p) => Some(p.to_path_buf()),
+        None => env::var_os(variable).filter(|v| !v.is_empty()).map(PathBuf::from),
+    }
+}
+
+fn first_existing(candidates: Vec<PathBuf>) -> std::result::Result<PathBuf, Vec<PathBuf>> {
+    match candidates.iter().find(|p| p.is_dir()) {
+        Some(p) => Ok(p.clone()),
+        None => Err(candidates),
+    }
+}
+
+fn home_dir() -> PathBuf {
+    match UserDirs::new() {
+        Some(dirs) => dirs.home_dir().to_path_buf(),
+        None => PathBuf::from("~"),
+    }
+}
+
+fn expand_home(path: &str, home: &Path) -> PathBuf {
+    match path.strip_prefix("~/") {
+        Some(rest) => home.join(rest),
+        None => PathBuf::from(path),
+    }
+}
+
+fn data_dir_candidates() -> Vec<PathBuf> {
+    let home = home_dir();
+
+    match env::consts::OS {
+        "linux" => {
+            let mut result: Vec<PathBuf> = steam_libraries(&home).iter().map(|l| l.join(GAME_DATA_IN_LIBRARY)).collect();
+            let drive_c = wine_prefix(&home).join("drive_c");
+            result.push(drive_c.join("Program Files (x86)/Steam").join(GAME_DATA_IN_LIBRARY));
+            result.push(drive_c.join("Program Files/Steam").join(GAME_DATA_IN_LIBRARY));
+            result
+        }
+        _ => POSSIBLE_DATA_DIRECTORIES.iter().map(|d| expand_home(d, &home)).collect(),
+    }
+}
+
+fn save_dir_candidates() -> Vec<PathBuf> {
+    let dirs = UserDirs::new();
+    let home = home_dir();
+
+    match env::consts::OS {
+        "windows" => match dirs.as_ref().and_then(|d| d.document_dir()) {
+            Some(docs) => vec![docs.join(SAVES_IN_DOCUMENTS)],
+            None => vec![home.join("Documents").join(SAVES_IN_DOCUMENTS)],
+        },
+        "macos" => vec![home.join("Library/Application Support/Megaquarium/Saves")],
         _ => {
-            panic!("unsupported OS")
+            // under proton the game sees a windows user profile inside the per-game prefix
+            let mut result: Vec<PathBuf> = steam_libraries(&home)
+                .iter()
+                .map(|l| {
+                    l.join("steamapps/compatdata")
+                        .join(STEAM_APP_ID)
+                        .join("pfx/drive_c/users/steamuser/Documents")
+                        .join(SAVES_IN_DOCUMENTS)
+                })
+                .collect();
+
+            let users = wine_prefix(&home).join("drive_c/users");
+            if let Ok(user) = env::var("USER") {
+                result.push(users.join(user).join("Documents").join(SAVES_IN_DOCUMENTS));
+            }
+            result.push(users.join("steamuser/Documents").join(SAVES_IN_DOCUMENTS));
+            result
         }
     }
 }
+
+fn wine_prefix(home: &Path) -> PathBuf {
+    match env::var_os("WINEPREFIX").filter(|v| !v.is_empty()) {
+        Some(p) => PathBuf::from(p),
+        None => home.join(".wine"),
+    }
+}
+
+/// All Steam library folders we can find: the well-known roots plus any extra libraries listed in
+/// their `libraryfolders.vdf`, deduplicated since the roots are often symlinks to each other.
+fn steam_libraries(home: &Path) -> Vec<PathBuf> {
+    let mut result: Vec<PathBuf> = Vec::new();
+
+    let mut push = |p: PathBuf| {
+        let key = fs::canonicalize(&p).unwrap_or_else(|_| p.clone());
+        if !result.iter().any(|r| fs::canonicalize(r).unwrap_or_else(|_| r.clone()) == key) {
+            result.push(p);
+        }
+    };
+
+    for root in LINUX_STEAM_ROOTS {
+        let root = expand_home(root, home);
+        let extra = library_folders(&root.join("steamapps/libraryfolders.vdf"));
+        push(root);
+        for p in extra {
+            push(p);
+        }
+    }
+
+    result
+}
+
+/// Pulls the `"path" "<dir>"` entries out of a Steam `libraryfolders.vdf`.
+fn library_folders(vdf: &Path) -> Vec<PathBuf> {
+    let contents = match fs::read_to_string(vdf) {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+
+    parse_library_folders(&contents)
+}
+
+fn parse_library_folders(contents: &str) -> Vec<PathBuf> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let rest = line.strip_prefix("\"path\"")?.trim();
+            let value = rest.strip_prefix('"')?.strip_suffix('"')?;
+            Some(PathBuf::from(value.replace("\\\\", "\\")))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::test::TempDir;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn test_parse_library_folders() {
+        let vdf = r#"
+"libraryfolders"
+{
+	"0"
+	{
+		"path"		"/home/eric/.local/share/Steam"
+		"label"		""
+	}
+	"1"
+	{
+		"path"		"/mnt/games/SteamLibrary"
+	}
+}
+"#;
+        assert_eq!(
+            parse_library_folders(vdf),
+            vec![
+                PathBuf::from("/home/eric/.local/share/Steam"),
+                PathBuf::from("/mnt/games/SteamLibrary")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_home() {
+        let home = Path::new("/home/eric");
+        assert_eq!(expand_home("~/.steam/steam", home), PathBuf::from("/home/eric/.steam/steam"));
+        assert_eq!(expand_home("C:/Steam", home), PathBuf::from("C:/Steam"));
+    }
+
+    #[test]
+    fn test_workshop_dir() {
+        let temp = TempDir::new("workshop");
+        let root = temp.path();
+        let data_dir = root
+            .join("steamapps")
+            .join(GAME_DATA_IN_LIBRARY.strip_prefix("steamapps/").unwrap());
+        let workshop = root.join("steamapps/workshop/content").join(STEAM_APP_ID);
+        fs::create_dir_all(workshop.join("222/Data")).unwrap();
+        fs::create_dir_all(workshop.join("111")).unwrap();
+        fs::write(workshop.join("222/Data/fish.data"), "{}").unwrap();
+        fs::write(workshop.join("222/readme.txt"), "").unwrap();
+
+        assert_eq!(workshop_dir(&data_dir), Some(workshop.clone()));
+        assert_eq!(subdirectories(&workshop), vec![workshop.join("111"), workshop.join("222")]);
+        assert_eq!(mod_data_files(&workshop.join("222")), vec![workshop.join("222/Data/fish.data")]);
+        assert_eq!(workshop_dir(Path::new("/opt/megaquarium/GameData")), None);
+    }
+
+    #[test]
+    fn test_language_files() {
+        let temp = TempDir::new("language");
+        let root = temp.path();
+        let dlc = root.join(Dlc::FreshwaterFrenzy.directory());
+        fs::create_dir_all(root.join(LANGUAGES_DIR)).unwrap();
+        fs::create_dir_all(dlc.join(LANGUAGES_DIR)).unwrap();
+        fs::write(root.join("Languages/en.lang"), "{}").unwrap();
+        fs::write(root.join("Languages/de.lang"), "{}").unwrap();
+        fs::write(root.join("Languages/notes.txt"), "").unwrap();
+        fs::write(dlc.join("Languages/en.lang"), "{}").unwrap();
+
+        assert_eq!(
+            language_files(root),
+            vec![
+                ("de".to_string(), "Languages/de.lang".to_string()),
+                ("en".to_string(), "Languages/en.lang".to_string()),
+                ("en".to_string(), "DLC/Freshwater Frenzy/Languages/en.lang".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_and_list_saves() {
+        let temp = TempDir::new("saves");
+        let dir = temp.path();
+        // explicit times, some filesystems only keep whole seconds
+        let write = |name: &str, seconds: u64| {
+            fs::write(dir.join(name), "{}").unwrap();
+            let file = fs::File::options().write(true).open(dir.join(name)).unwrap();
+            file.set_modified(UNIX_EPOCH + Duration::from_secs(seconds)).unwrap();
+        };
+        write("new.sav", 1_700_000_100);
+        write("old.sav", 1_700_000_000);
+        write("notes.txt", 1_700_000_200);
+
+        let names: Vec<String> = list_saves(dir).unwrap().into_iter().map(|s| s.name).collect();
+        assert_eq!(names, vec!["new", "old"]);
+        assert_eq!(latest_save(Some(dir)).unwrap(), dir.join("new.sav"));
+
+        assert_eq!(find_save("old", Some(dir)).unwrap(), dir.join("old.sav"));
+        assert_eq!(find_save("backup.sav", None).unwrap(), PathBuf::from("backup.sav"));
+        assert_eq!(find_save("saves/park", None).unwrap(), PathBuf::from("saves/park"));
+    }
+
+    #[test]
+    fn test_explicit_dir_is_only_candidate() {
+        let missing = Path::new("/definitely/not/a/megaquarium/dir");
+        let err = find_data_dir(Some(missing)).unwrap_err().to_string();
+        assert!(err.contains("/definitely/not/a/megaquarium/dir"));
+        assert!(err.contains("--data-dir"));
+    }
+}
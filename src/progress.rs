@@ -0,0 +1,37 @@
+// pattern: Functional Core
+
+/// How far the player has come in a save: their research level, and anything they've researched
+/// individually ahead of that level.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Progress {
+    pub level: u8,
+    pub unlocked: Vec<String>,
+}
+
+impl Progress {
+    /// `unlock_level` is None for content that is available from the start.
+    pub fn allows(&self, id: &str, unlock_level: Option<u8>) -> bool {
+        match unlock_level {
+            None => true,
+            Some(l) => l <= self.level || self.unlocked.iter().any(|u| u == id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_allows() {
+        let progress = Progress {
+            level: 2,
+            unlocked: vec!["lionfish".to_string()],
+        };
+
+        assert!(progress.allows("clown_fish", None));
+        assert!(progress.allows("tang", Some(2)));
+        assert!(!progress.allows("shark", Some(3)));
+        assert!(progress.allows("lionfish", Some(5)));
+    }
+}
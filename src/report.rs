@@ -1,15 +1,47 @@
 // pattern: Imperative Shell
 
+use std::collections::BTreeSet;
 use std::fmt::Display;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::check::{AquariumCheckResult, CheckQuery, ExhibitCheckResult, ExhibitValidation};
+use crate::animal::{AnimalId, Breeding, Diet, Species};
+use crate::audit::UnknownKey;
+use crate::check::{AquariumCheckResult, CheckQuery, ExhibitCheckResult, ExhibitValidation, FoodAmount};
+use crate::compare::{Agreement, ExhibitComparison};
+use crate::data::{GameData, SaveDiagnostic};
+use crate::data_diff::{Change, EntityChange, Verdict, VerdictChange};
+use crate::economy::ExhibitEconomy;
+use crate::lifecycle::LifeStage;
+use crate::names::Names;
+use crate::park::{FeedingCoverage, ParkState};
+use crate::paths::SaveFile;
 use crate::rules::Violation;
+use crate::save_diff::SaveChange;
 use crate::sexpr_format::PrettyPrinted;
 use crate::sexpr_format::ToSexp;
 use crate::tank::Environment;
+use crate::util::Result;
+use crate::watch::ProblemChanges;
 
-pub fn print_violations(violations: &[Violation]) {
-    let mut messages: Vec<_> = violations.iter().map(|v| v.to_string()).collect();
+/// What the game calls something, with the id to use on the command line.
+pub fn named(id: &str, names: &Names, lang: &str) -> String {
+    match names.get(lang) {
+        Some(name) => format!("{} ({})", name, id),
+        None => id.to_string(),
+    }
+}
+
+/// `named` for text that only has species ids, like violations.
+pub fn species_namer<'a>(data: &'a GameData, lang: &'a str) -> impl Fn(&str) -> String + 'a {
+    move |id| match data.try_species_ref(id) {
+        Some(s) => named(id, &s.names, lang),
+        None => id.to_string(),
+    }
+}
+
+pub fn print_violations(violations: &[Violation], name: &dyn Fn(&str) -> String) {
+    let mut messages: Vec<_> = violations.iter().map(|v| v.describe(name)).collect();
     messages.sort();
     messages.dedup();
 
@@ -18,10 +50,76 @@ pub fn print_violations(violations: &[Violation]) {
     }
 }
 
-pub fn print_exhibit_result(args: &CheckQuery, result: &ExhibitCheckResult) {
+/// Like `print_violations`, but lists the animals each one is about so they can be found in game.
+/// Ids in `numbered` were made up for summarized animals and are left out.
+fn print_violations_with_animals(violations: &[Violation], name: &dyn Fn(&str) -> String, numbered: &BTreeSet<AnimalId>) {
+    let mut messages: Vec<(String, Vec<AnimalId>)> = Vec::new();
+
+    for v in violations {
+        let message = v.describe(name);
+        let ids = std::iter::once(v.animal.id)
+            .chain(v.conflicting.as_ref().map(|c| c.id))
+            .filter(|id| !numbered.contains(id));
+        match messages.iter_mut().find(|(m, _)| *m == message) {
+            Some((_, list)) => list.extend(ids),
+            None => messages.push((message, ids.collect())),
+        }
+    }
+    messages.sort();
+
+    for (message, mut ids) in messages {
+        ids.sort_unstable();
+        ids.dedup();
+        if ids.is_empty() {
+            println!("- {}", message);
+            continue;
+        }
+        let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+        let noun = if ids.len() == 1 { "animal" } else { "animals" };
+        println!("- {} ({} {})", message, noun, ids.join(", "));
+    }
+}
+
+fn print_food(food: &[FoodAmount]) {
+    for item in food {
+        let mut notes = Vec::new();
+        if item.skill > 0 {
+            notes.push(format!("skill {}", item.skill));
+        }
+        if let Some(s) = &item.storage {
+            notes.push(format!("stored {}", s));
+        }
+        if let Some(p) = &item.preparation {
+            notes.push(format!("prepared at {}", p));
+        }
+        if let Some(c) = item.cost {
+            notes.push(format!("costs {}", c));
+        }
+
+        if notes.is_empty() {
+            println!("- {}x {}", item.count, item.food);
+        } else {
+            println!("- {}x {} ({})", item.count, item.food, notes.join(", "));
+        }
+    }
+}
+
+fn print_economy(economy: &ExhibitEconomy) {
+    if !economy.is_empty() {
+        println!(
+            "- build cost: {}, running cost: {}/day, prestige: {}",
+            economy.build_cost, economy.running_cost, economy.prestige
+        );
+    }
+}
+
+pub fn print_exhibit_result(args: &CheckQuery, result: &ExhibitCheckResult, lang: &str) {
     println!("For contents:");
     for c in &args.counts {
-        println!("- {}x {}", c.count, c.species);
+        match args.animals.iter().find(|a| a.species.id == c.species) {
+            Some(a) => println!("- {}x {}", c.count, named(&c.species, &a.species.names, lang)),
+            None => println!("- {}x {}", c.count, c.species),
+        }
     }
 
     if result.is_okay() {
@@ -38,16 +136,19 @@ pub fn print_exhibit_result(args: &CheckQuery, result: &ExhibitCheckResult) {
         }
 
         println!("\nWill require food (average per day):");
-        for item in &result.food {
-            if item.skill > 0 {
-                println!("- {}x {} (skill {})", item.count, item.food, item.skill);
-            } else {
-                println!("- {}x {}", item.count, item.food);
-            }
+        print_food(&result.food);
+
+        if !result.economy.is_empty() {
+            println!("\nAnimals and food alone will cost:");
+            print_economy(&result.economy);
         }
     } else {
         println!("\nA valid tank is not possible:");
-        print_violations(&result.violations);
+        let name = |id: &str| match args.animals.iter().find(|a| a.species.id == id) {
+            Some(a) => named(id, &a.species.names, lang),
+            None => id.to_string(),
+        };
+        print_violations(&result.violations, &name);
     }
 }
 
@@ -131,33 +232,319 @@ fn print_exhibit_environment(exhibit: &ExhibitValidation) {
             v
         );
     }
+
+    let ls = &exhibit.life_support;
+    if !ls.is_empty() {
+        let parts: Vec<String> = [
+            ("filtration", ls.filtration),
+            ("heating", ls.heating),
+            ("cooling", ls.cooling),
+            ("skimming", ls.skimming),
+            ("lighting", ls.lighting),
+        ]
+        .iter()
+        .filter(|(_, v)| *v > 0)
+        .map(|(k, v)| format!("{} {}", k, v))
+        .collect();
+        println!("- life support: {}", parts.join(", "));
+    }
+
+    if let Some(w) = &exhibit.water {
+        println!("- achievable quality: {}% (bioload {}/{})", w.achievable, w.bioload, w.capacity);
+    }
 }
 
-pub fn print_aquarium_result(result: &AquariumCheckResult, debug: bool) {
+pub fn print_aquarium_result(result: &AquariumCheckResult, debug: bool, name: &dyn Fn(&str) -> String, numbered: &BTreeSet<AnimalId>) {
     println!("Checking {} tanks...", result.exhibits.len());
 
-    for exhibit in &result.exhibits {
-        println!("{}:", exhibit.name);
+    // grouped by floor and zone, in the save's order within each
+    let mut exhibits: Vec<&ExhibitValidation> = result.exhibits.iter().collect();
+    exhibits.sort_by_key(|e| (e.location.floor, e.location.zone.clone()));
+
+    for exhibit in exhibits {
+        match exhibit.location.label() {
+            Some(label) => println!("{} ({}):", exhibit.name, label),
+            None => println!("{}:", exhibit.name),
+        }
 
         if debug {
             println!("loaded: {:#?}", exhibit.loaded_environment);
             println!("needed: {:#?}", exhibit.minimum_viable_environment);
+            println!("life support: {:#?}", exhibit.life_support);
+            println!("water: {:#?}", exhibit.water);
         } else {
             print_exhibit_environment(exhibit);
         }
 
-        for item in &exhibit.food {
-            if item.skill > 0 {
-                println!("- {}x {} (skill {})", item.count, item.food, item.skill);
+        print_food(&exhibit.food);
+        print_economy(&exhibit.economy);
+
+        print_violations_with_animals(&exhibit.violations, name, numbered);
+    }
+
+    if result.is_okay() {
+        println!("No problems!");
+    }
+}
+
+pub fn print_life_cycle(adult: &Species, stages: &[LifeStage], lang: &str) {
+    println!("{}:", named(&adult.id, &adult.names, lang));
+
+    match &adult.breeding {
+        Breeding::Breedable(b) => {
+            let mut notes: Vec<String> = b.conditions.iter().map(|c| c.to_string()).collect();
+            if let Some(i) = b.interval {
+                notes.insert(0, format!("every {} days", i));
+            }
+            if notes.is_empty() {
+                println!("Breeds into {}", b.baby);
             } else {
-                println!("- {}x {}", item.count, item.food);
+                println!("Breeds into {} ({})", b.baby, notes.join(", "));
             }
         }
+        _ => println!("Does not breed"),
+    }
 
-        print_violations(&exhibit.violations);
+    for stage in stages {
+        let duration = match stage.duration {
+            Some(d) => format!("for {} days", d),
+            None if std::ptr::eq(stage.species, adult) => "when fully grown".to_string(),
+            None => "for unknown days".to_string(),
+        };
+
+        let habitat = &stage.species.habitat;
+        let mut needs = vec![format!("quality {}", habitat.minimum_quality), habitat.temperature.to_string()];
+        if let Some(s) = habitat.salinity {
+            needs.push(s.to_string());
+        }
+        match &stage.species.diet {
+            Diet::Food { food, .. } => needs.push(format!("eats {}", food)),
+            Diet::Scavenger => needs.push("scavenges".to_string()),
+            Diet::DoesNotEat => (),
+        }
+
+        let name = named(&stage.species.id, &stage.species.names, lang);
+        println!("- {} size {} {}: {}", name, stage.size, duration, needs.join(", "));
     }
+}
 
-    if result.is_okay() {
-        println!("No problems!");
+pub fn print_audit(unknown: &[UnknownKey], all: bool) {
+    if unknown.is_empty() {
+        println!("The loaders understand every key");
+        return;
+    }
+
+    let mut section = "";
+    for u in unknown {
+        if u.section != section {
+            section = u.section;
+            println!("{}:", section);
+        }
+
+        let shown = if all { u.ids.len() } else { u.ids.len().min(3) };
+        let more = if shown < u.ids.len() { ", ..." } else { "" };
+        let noun = if u.ids.len() == 1 { "object" } else { "objects" };
+        println!("- {} ({} {}: {}{})", u.key, u.ids.len(), noun, u.ids[..shown].join(", "), more);
+    }
+}
+
+pub fn print_data_diff(changes: &[EntityChange]) {
+    if changes.is_empty() {
+        println!("No differences.");
+        return;
+    }
+
+    for c in changes {
+        match &c.change {
+            Change::Added => println!("+ {} {}", c.kind, c.id),
+            Change::Removed => println!("- {} {}", c.kind, c.id),
+            Change::Changed(fields) => {
+                println!("~ {} {}", c.kind, c.id);
+                for f in fields {
+                    println!("    {}: {} -> {}", f.field, f.old, f.new);
+                }
+            }
+        }
+    }
+}
+
+pub fn print_verdict_changes(changes: &[VerdictChange]) {
+    fn describe(v: &Verdict) -> String {
+        match v {
+            Verdict::Valid => "valid".to_string(),
+            Verdict::Invalid(messages) => format!("{} problem(s)", messages.len()),
+            Verdict::Unloadable(e) => format!("cannot load ({})", e),
+        }
+    }
+
+    fn messages(v: &Verdict) -> &[String] {
+        match v {
+            Verdict::Invalid(messages) => messages,
+            _ => &[],
+        }
+    }
+
+    if changes.is_empty() {
+        println!("No exhibit's verdict changed.");
+        return;
+    }
+
+    for c in changes {
+        println!("{}: {} -> {}", c.exhibit, describe(&c.old), describe(&c.new));
+        for m in messages(&c.new) {
+            if !messages(&c.old).contains(m) {
+                println!("- new: {}", m);
+            }
+        }
+        for m in messages(&c.old) {
+            if !messages(&c.new).contains(m) {
+                println!("- resolved: {}", m);
+            }
+        }
+    }
+}
+
+pub fn print_saves(save_dir: &Path, saves: &[SaveFile], exhibits: &[Result<usize>]) {
+    if saves.is_empty() {
+        println!("No saves in {}", save_dir.display());
+        return;
+    }
+
+    println!("Saves in {} (most recent first):", save_dir.display());
+    for (save, exhibits) in saves.iter().zip(exhibits) {
+        let modified = match save.modified {
+            Some(m) => format_time(m),
+            None => "unknown time".to_string(),
+        };
+        match exhibits {
+            Ok(1) => println!("- {} ({}, 1 exhibit)", save.name, modified),
+            Ok(n) => println!("- {} ({}, {} exhibits)", save.name, modified, n),
+            Err(e) => println!("- {} ({}, unreadable: {})", save.name, modified, e),
+        }
+    }
+}
+
+/// Formats a time as a UTC date and time, e.g. `2024-03-01 18:30 UTC`.
+fn format_time(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let (days, rest) = ((secs / 86400) as i64, secs % 86400);
+
+    // days since the epoch to a civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02} {:02}:{:02} UTC", year, month, day, rest / 3600, rest % 3600 / 60)
+}
+
+/// `first` is whether this is the first time the save was validated, so every problem is new.
+pub fn print_problem_changes(save: &Path, changes: &[ProblemChanges], first: bool) {
+    let name = save.file_stem().unwrap_or(save.as_os_str()).to_string_lossy();
+
+    if changes.is_empty() {
+        if first {
+            println!("{}: no problems", name);
+        } else {
+            println!("{}: no new or resolved problems", name);
+        }
+        return;
+    }
+
+    println!("{}:", name);
+    for c in changes {
+        println!("{}:", c.exhibit);
+        for p in &c.introduced {
+            println!("+ {}", p);
+        }
+        for p in &c.resolved {
+            println!("- {}", p);
+        }
+    }
+}
+
+pub fn print_save_diff(changes: &[SaveChange]) {
+    if changes.is_empty() {
+        println!("No changes");
+    }
+
+    for c in changes {
+        println!("{}", c);
+    }
+}
+
+pub fn print_park(park: &ParkState, coverage: &[FeedingCoverage]) {
+    let unknown = || "unknown".to_string();
+    println!("Money: {}", park.money.map(|m| m.to_string()).unwrap_or_else(unknown));
+    println!("Prestige: {}", park.prestige.map(|p| p.to_string()).unwrap_or_else(unknown));
+    println!("Guests: {}", park.guests);
+
+    println!("\nStaff:");
+    if park.staff.is_empty() {
+        println!("- none");
+    }
+    for s in &park.staff {
+        println!("- {}: feeding {}, maintenance {}", s.label(), s.feeding, s.maintenance);
+    }
+
+    if coverage.is_empty() {
+        return;
+    }
+
+    println!("\nFeeding:");
+    let feeder = park.best_feeder().map(|s| s.label());
+    for c in coverage {
+        match &feeder {
+            Some(f) if c.is_covered() => println!("- {} needs skill {} for {}, {} can", c.exhibit, c.needed, c.food, f),
+            _ => println!(
+                "- {} needs skill {} for {} but the best feeder has {}",
+                c.exhibit, c.needed, c.food, c.available
+            ),
+        }
+    }
+}
+
+pub fn print_game_comparison(exhibits: &[ExhibitComparison], name: &dyn Fn(&str) -> String) {
+    for exhibit in exhibits {
+        println!("{}:", exhibit.exhibit);
+        if exhibit.animals.is_empty() {
+            println!("- no complaints from the game or us");
+        }
+
+        for a in &exhibit.animals {
+            let mut state = Vec::new();
+            if let Some(h) = a.state.health {
+                state.push(format!("health {}%", h));
+            }
+            if let Some(h) = a.state.happiness {
+                state.push(format!("happiness {}%", h));
+            }
+            let verdict = match a.agreement() {
+                Agreement::Both => "both",
+                Agreement::OnlyGame => "only the game",
+                Agreement::OnlyOurs => "only us",
+            };
+            state.push(format!("flagged by {}", verdict));
+
+            println!("- animal {} ({}): {}", a.animal, name(&a.species), state.join(", "));
+            for n in &a.state.unmet_needs {
+                println!("  game: {}", n);
+            }
+            for v in &a.ours {
+                println!("  ours: {}", v.describe(name));
+            }
+        }
+    }
+}
+
+/// On stderr, so they don't get mixed into output meant for other commands.
+pub fn print_save_warnings(diagnostics: &[SaveDiagnostic]) {
+    for d in diagnostics {
+        eprintln!("warning: {}", d);
     }
 }
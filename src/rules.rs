@@ -3,6 +3,7 @@
 use crate::{
     animal::{Animal, AnimalRef, Cohabitation, Diet, Growth, Need, PreyType, Shoaling},
     tank,
+    water::WaterQuality,
 };
 use Constraint::*;
 
@@ -23,8 +24,16 @@ pub enum Constraint {
     Interior(tank::Interior),
     TankSize(u16),
     Territorial,
-    Predator { prey: PreyType, size: u16 },
+    Predator {
+        prey: PreyType,
+        size: u16,
+    },
     Communal(u8),
+    /// the species needs `needed` quality but the exhibit's life support only achieves `achievable`
+    Filtration {
+        needed: u8,
+        achievable: u8,
+    },
 }
 
 pub struct ExhibitSpec<'a> {
@@ -41,55 +50,67 @@ pub struct Violation {
 
 impl std::fmt::Display for Violation {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let s = &self.animal.species;
-
-        match (&self.constraint, &self.conflicting) {
-            (Temperature(t), None) => write!(f, "{} requires {} tank", s, t),
-            (Temperature(t), Some(o)) => write!(f, "{} requires {} tank but {} requires {}", s, t, o.species, t.other()),
-            (Salinity(x), None) => write!(f, "{} requires {} tank", s, x),
-            (Salinity(x), Some(o)) => write!(f, "{} requires {} tank but {} requires {}", s, x, o.species, x.other()),
-            (Quality(q), _) => write!(f, "{} requires at least quality {}", s, q),
+        write!(f, "{}", self.describe(&|id| id.to_string()))
+    }
+}
+
+impl Violation {
+    /// The violation as text, with `name` turning species ids into what to show for them.
+    pub fn describe(&self, name: &dyn Fn(&str) -> String) -> String {
+        let s = name(&self.animal.species);
+        let other = self.conflicting.as_ref().map(|o| (o, name(&o.species)));
+
+        match (&self.constraint, &other) {
+            (Temperature(t), None) => format!("{} requires {} tank", s, t),
+            (Temperature(t), Some((_, n))) => format!("{} requires {} tank but {} requires {}", s, t, n, t.other()),
+            (Salinity(x), None) => format!("{} requires {} tank", s, x),
+            (Salinity(x), Some((_, n))) => format!("{} requires {} tank but {} requires {}", s, x, n, x.other()),
+            (Quality(q), _) => format!("{} requires at least quality {}", s, q),
             (Shoaler(c), _) => {
                 let or1 = if c.one_ok { ", or 1" } else { "" };
                 let or2 = if c.one_ok { ", or 2" } else { "" };
-                write!(f, "{} is a shoaler and needs {} of its species{}{}", s, c.count, or1, or2)
+                format!("{} is a shoaler and needs {} of its species{}{}", s, c.count, or1, or2)
             }
-            (NoBully, Some(o)) => write!(f, "{} will bully {}", o.species, s),
-            (NoNibbler, Some(o)) => write!(f, "{} will nibble {}", o.species, s),
-            (Lighting(Need::Dislikes), None) => write!(f, "{} requires no light", s),
-            (Lighting(Need::Dislikes), Some(o)) => {
-                write!(f, "{} requires no light but {} needs light", s, o.species)
+            (NoBully, Some((_, n))) => format!("{} will bully {}", n, s),
+            (NoNibbler, Some((_, n))) => format!("{} will nibble {}", n, s),
+            (Lighting(Need::Dislikes), None) => format!("{} requires no light", s),
+            (Lighting(Need::Dislikes), Some((_, n))) => {
+                format!("{} requires no light but {} needs light", s, n)
             }
-            (Lighting(Need::Loves(l)), _) => write!(f, "{} requires at least {} light", s, l),
-            (Cohabitation(Cohabitation::OnlyCongeners), Some(o)) => {
-                write!(f, "{} requires congeners but there is {}", s, o.species)
+            (Lighting(Need::Loves(l)), _) => format!("{} requires at least {} light", s, l),
+            (Cohabitation(Cohabitation::OnlyCongeners), Some((_, n))) => {
+                format!("{} requires congeners but there is {}", s, n)
             }
-            (Cohabitation(Cohabitation::NoCongeners), Some(o)) => {
-                if *s == o.species {
-                    write!(f, "{} cannot be with congeners but there are multiple {}", s, o.species)
+            (Cohabitation(Cohabitation::NoCongeners), Some((o, n))) => {
+                if self.animal.species == o.species {
+                    format!("{} cannot be with congeners but there are multiple {}", s, n)
                 } else {
-                    write!(f, "{} cannot be with congeners but there is {}", s, o.species)
+                    format!("{} cannot be with congeners but there is {}", s, n)
                 }
             }
             (Cohabitation(Cohabitation::NoConspecifics), _) => {
-                write!(f, "{} cannot be with its own species but there are multiple", s)
+                format!("{} cannot be with its own species but there are multiple", s)
             }
-            (Cohabitation(Cohabitation::PairsOnly), _) => write!(f, "{} must only be a multiple of two", s),
-            (Cohabitation(Cohabitation::NoFoodCompetitors), Some(o)) => {
-                write!(f, "{} will compete for food with {}", s, o.species)
+            (Cohabitation(Cohabitation::PairsOnly), _) => format!("{} must only be a multiple of two", s),
+            (Cohabitation(Cohabitation::NoFoodCompetitors), Some((_, n))) => {
+                format!("{} will compete for food with {}", s, n)
             }
-            (Interior(tank::Interior::Rounded), _) => write!(f, "{} requies a rounded tank", s),
-            (Interior(tank::Interior::Kreisel), _) => write!(f, "{} requies a kreisel tank", s),
-            (Territorial, _) => write!(f, "{} is territorial, total size can only be 50% of tank size", s),
-            (Predator { prey: _, size: _ }, Some(o)) => {
+            (Interior(tank::Interior::Rounded), _) => format!("{} requies a rounded tank", s),
+            (Interior(tank::Interior::Kreisel), _) => format!("{} requies a kreisel tank", s),
+            (Territorial, _) => format!("{} is territorial, total size can only be 50% of tank size", s),
+            (Predator { prey: _, size: _ }, Some((o, n))) => {
                 if o.growth != Growth::Final {
                     // TODO need to determine this completely
-                    write!(f, "{} will eat {} (though may be fine if fully grown)", s, o.species)
+                    format!("{} will eat {} (though may be fine if fully grown)", s, n)
                 } else {
-                    write!(f, "{} will eat {}", s, o.species)
+                    format!("{} will eat {}", s, n)
                 }
             }
-            (Communal(others), _) => write!(f, "{} is communal and requires at least {} other species", s, others),
+            (Communal(others), _) => format!("{} is communal and requires at least {} other species", s, others),
+            (Filtration { needed, achievable }, _) => format!(
+                "{} requires at least quality {} but the life support can only keep the water at {}",
+                s, needed, achievable
+            ),
             _ => todo!(),
         }
     }
@@ -109,6 +130,24 @@ pub fn find_violations(exhibit: &ExhibitSpec) -> Vec<Violation> {
     result
 }
 
+/// Checks every animal's minimum quality against what the exhibit's life support can achieve.
+/// This is separate from `find_violations` since it depends on the actual equipment rather than
+/// the minimum viable environment.
+pub fn find_water_quality_violations(animals: &[AnimalRef], water: &WaterQuality) -> Vec<Violation> {
+    animals
+        .iter()
+        .filter(|a| a.species.habitat.minimum_quality > water.achievable)
+        .map(|a| Violation {
+            animal: a.to_animal(),
+            constraint: Filtration {
+                needed: a.species.habitat.minimum_quality,
+                achievable: water.achievable,
+            },
+            conflicting: None,
+        })
+        .collect()
+}
+
 fn check_constraint<'a>(exhibit: &'a ExhibitSpec<'a>, anim: &'a AnimalRef<'a>, constraint: &Constraint) -> Option<Violation> {
     let simple = |is_okay: bool| {
         if is_okay {
@@ -221,6 +260,8 @@ fn check_constraint<'a>(exhibit: &'a ExhibitSpec<'a>, anim: &'a AnimalRef<'a>, c
             if_conflict(exhibit.animals.iter().find(can_eat))
         }
         Communal(others) => simple(count_distinct_by(exhibit.animals, |a| &a.species.id) > (*others as usize)),
+        // never one of a species' own constraints, see find_water_quality_violations
+        Filtration { .. } => None,
     }
 }
 
@@ -324,6 +365,23 @@ pub mod test {
         s
     }
 
+    #[test]
+    fn test_describe_names_species() {
+        let piranha = test_species("piranha");
+        let tang = test_species("tang");
+        let violation = Violation {
+            animal: make_animal(&piranha).to_animal(),
+            constraint: Temperature(tank::Temperature::Warm),
+            conflicting: Some(make_animal(&tang).to_animal()),
+        };
+
+        assert_eq!(violation.to_string(), "piranha requires warm tank but tang requires cold");
+        assert_eq!(
+            violation.describe(&|id| format!("the {}", id)),
+            "the piranha requires warm tank but the tang requires cold"
+        );
+    }
+
     #[test]
     fn test_temperature() {
         let species = test_species("test");
@@ -393,6 +451,38 @@ pub mod test {
         assert_eq!(check_constraint(&q65_exhibit, &animal, &q65_constraint), None);
     }
 
+    #[test]
+    fn test_water_quality() {
+        let picky = Species {
+            habitat: Habitat {
+                minimum_quality: 80,
+                ..test_species("picky").habitat
+            },
+            ..test_species("picky")
+        };
+        let hardy = test_species("hardy"); // quality 55
+        let animals = vec![make_animal(&picky), make_animal(&hardy)];
+
+        let water = |achievable| WaterQuality {
+            bioload: 10,
+            capacity: 10,
+            achievable,
+        };
+
+        assert_eq!(find_water_quality_violations(&animals, &water(80)), vec![]);
+        assert_eq!(
+            find_water_quality_violations(&animals, &water(70)),
+            vec![simple_violation(
+                &animals[0],
+                Filtration {
+                    needed: 80,
+                    achievable: 70
+                }
+            )]
+        );
+        assert_eq!(find_water_quality_violations(&animals, &water(50)).len(), 2);
+    }
+
     #[test]
     fn test_salinity() {
         let species = test_species("test");
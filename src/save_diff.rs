@@ -0,0 +1,339 @@
+// pattern: Functional Core
+
+// Compares two saves of the same park (e.g., before and after a play session) by the uids the game
+// gives tanks, animals and fixtures, so we can see what happened to each of them.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::animal::{AnimalId, AnimalRef, Growth};
+use crate::aquarium::{AquariumRef, ExhibitRef};
+use crate::fixture::{FixtureId, FixtureRef};
+
+#[derive(Debug, PartialEq)]
+pub enum SaveChange {
+    TankAdded {
+        tank: u64,
+        exhibit: String,
+        size: (u16, u16),
+    },
+    TankRemoved {
+        tank: u64,
+        exhibit: String,
+    },
+    TankResized {
+        tank: u64,
+        exhibit: String,
+        old: (u16, u16),
+        new: (u16, u16),
+    },
+    AnimalAdded {
+        animal: AnimalId,
+        species: String,
+        exhibit: String,
+    },
+    /// sold, died or, most likely, eaten
+    AnimalDisappeared {
+        animal: AnimalId,
+        species: String,
+        exhibit: String,
+    },
+    AnimalMoved {
+        animal: AnimalId,
+        species: String,
+        from: String,
+        to: String,
+    },
+    /// only reported when the animal reaches a new stage, not for growth within a stage
+    AnimalGrew {
+        animal: AnimalId,
+        species: String,
+        exhibit: String,
+        old: Growth,
+        new: Growth,
+    },
+    FixtureAdded {
+        fixture: FixtureId,
+        model: String,
+        exhibit: String,
+    },
+    FixtureRemoved {
+        fixture: FixtureId,
+        model: String,
+        exhibit: String,
+    },
+    FixtureMoved {
+        fixture: FixtureId,
+        model: String,
+        from: String,
+        to: String,
+    },
+}
+
+use SaveChange::*;
+
+fn stage(growth: Growth) -> String {
+    match growth {
+        Growth::Final => "fully grown".to_string(),
+        Growth::Growing { stage, .. } => format!("stage {}", stage),
+    }
+}
+
+impl fmt::Display for SaveChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TankAdded { tank, exhibit, size } => write!(f, "+ tank {} ({}) {}x{}", tank, exhibit, size.0, size.1),
+            TankRemoved { tank, exhibit } => write!(f, "- tank {} ({})", tank, exhibit),
+            TankResized { tank, exhibit, old, new } => {
+                write!(
+                    f,
+                    "~ tank {} ({}) resized from {}x{} to {}x{}",
+                    tank, exhibit, old.0, old.1, new.0, new.1
+                )
+            }
+            AnimalAdded { animal, species, exhibit } => write!(f, "+ animal {} ({}) in {}", animal, species, exhibit),
+            AnimalDisappeared { animal, species, exhibit } => {
+                write!(f, "- animal {} ({}) disappeared from {}", animal, species, exhibit)
+            }
+            AnimalMoved { animal, species, from, to } => {
+                write!(f, "~ animal {} ({}) moved from {} to {}", animal, species, from, to)
+            }
+            AnimalGrew {
+                animal,
+                species,
+                exhibit,
+                old,
+                new,
+            } => write!(
+                f,
+                "~ animal {} ({}) in {} grew from {} to {}",
+                animal,
+                species,
+                exhibit,
+                stage(*old),
+                stage(*new)
+            ),
+            FixtureAdded { fixture, model, exhibit } => write!(f, "+ fixture {} ({}) in {}", fixture, model, exhibit),
+            FixtureRemoved { fixture, model, exhibit } => write!(f, "- fixture {} ({}) from {}", fixture, model, exhibit),
+            FixtureMoved { fixture, model, from, to } => {
+                write!(f, "~ fixture {} ({}) moved from {} to {}", fixture, model, from, to)
+            }
+        }
+    }
+}
+
+fn same_stage(a: Growth, b: Growth) -> bool {
+    match (a, b) {
+        (Growth::Final, Growth::Final) => true,
+        (Growth::Growing { stage: x, .. }, Growth::Growing { stage: y, .. }) => x == y,
+        _ => false,
+    }
+}
+
+fn tanks<'a, 'b>(aquarium: &'b AquariumRef<'a>) -> BTreeMap<u64, &'b ExhibitRef<'a>> {
+    aquarium.exhibits.iter().map(|e| (e.tank.id, e)).collect()
+}
+
+/// Every animal by uid, with the exhibit it's in.
+fn animals<'a, 'b>(aquarium: &'b AquariumRef<'a>) -> BTreeMap<AnimalId, (&'b ExhibitRef<'a>, &'b AnimalRef<'a>)> {
+    let all = aquarium.exhibits.iter().flat_map(|e| e.animals.iter().map(move |a| (a.id, (e, a))));
+    all.collect()
+}
+
+/// Every fixture by uid, with the exhibit it's in.
+fn fixtures<'a, 'b>(aquarium: &'b AquariumRef<'a>) -> BTreeMap<FixtureId, (&'b ExhibitRef<'a>, &'b FixtureRef<'a>)> {
+    let all = aquarium
+        .exhibits
+        .iter()
+        .flat_map(|e| e.fixtures.iter().map(move |x| (x.id, (e, x))));
+    all.collect()
+}
+
+/// Everything that changed between two saves: tanks first, then animals, then fixtures, each in uid
+/// order.
+pub fn diff_saves(old: &AquariumRef, new: &AquariumRef) -> Vec<SaveChange> {
+    let mut result = Vec::new();
+
+    let (old_tanks, new_tanks) = (tanks(old), tanks(new));
+    for (id, e) in &old_tanks {
+        match new_tanks.get(id) {
+            None => result.push(TankRemoved {
+                tank: *id,
+                exhibit: e.name.clone(),
+            }),
+            Some(n) if n.tank.size != e.tank.size => result.push(TankResized {
+                tank: *id,
+                exhibit: n.name.clone(),
+                old: e.tank.size,
+                new: n.tank.size,
+            }),
+            Some(_) => (),
+        }
+    }
+    for (id, e) in new_tanks.iter().filter(|(id, _)| !old_tanks.contains_key(id)) {
+        result.push(TankAdded {
+            tank: *id,
+            exhibit: e.name.clone(),
+            size: e.tank.size,
+        });
+    }
+
+    let (old_animals, new_animals) = (animals(old), animals(new));
+    let ids: BTreeSet<_> = old_animals.keys().chain(new_animals.keys()).collect();
+    for id in ids {
+        match (old_animals.get(id), new_animals.get(id)) {
+            (Some((exhibit, a)), None) => result.push(AnimalDisappeared {
+                animal: *id,
+                species: a.species.id.clone(),
+                exhibit: exhibit.name.clone(),
+            }),
+            (None, Some((exhibit, a))) => result.push(AnimalAdded {
+                animal: *id,
+                species: a.species.id.clone(),
+                exhibit: exhibit.name.clone(),
+            }),
+            (Some((from, a)), Some((to, b))) => {
+                if from.tank.id != to.tank.id {
+                    result.push(AnimalMoved {
+                        animal: *id,
+                        species: b.species.id.clone(),
+                        from: from.name.clone(),
+                        to: to.name.clone(),
+                    });
+                }
+                if !same_stage(a.growth, b.growth) {
+                    result.push(AnimalGrew {
+                        animal: *id,
+                        species: b.species.id.clone(),
+                        exhibit: to.name.clone(),
+                        old: a.growth,
+                        new: b.growth,
+                    });
+                }
+            }
+            (None, None) => unreachable!(),
+        }
+    }
+
+    let (old_fixtures, new_fixtures) = (fixtures(old), fixtures(new));
+    let ids: BTreeSet<_> = old_fixtures.keys().chain(new_fixtures.keys()).collect();
+    for id in ids {
+        match (old_fixtures.get(id), new_fixtures.get(id)) {
+            (Some((exhibit, x)), None) => result.push(FixtureRemoved {
+                fixture: *id,
+                model: x.model.id.clone(),
+                exhibit: exhibit.name.clone(),
+            }),
+            (None, Some((exhibit, x))) => result.push(FixtureAdded {
+                fixture: *id,
+                model: x.model.id.clone(),
+                exhibit: exhibit.name.clone(),
+            }),
+            (Some((from, _)), Some((to, x))) if from.tank.id != to.tank.id => result.push(FixtureMoved {
+                fixture: *id,
+                model: x.model.id.clone(),
+                from: from.name.clone(),
+                to: to.name.clone(),
+            }),
+            _ => (),
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::animal::test::test_species;
+    use crate::fixture::test::test_fixture_model;
+    use crate::tank::test::test_tank_model;
+    use crate::tank::TankRef;
+
+    fn exhibit<'a>(
+        name: &str,
+        tank: u64,
+        model: &'a crate::tank::TankModel,
+        size: (u16, u16),
+        animals: Vec<AnimalRef<'a>>,
+        fixtures: Vec<FixtureRef<'a>>,
+    ) -> ExhibitRef<'a> {
+        ExhibitRef {
+            name: name.to_string(),
+            tank: TankRef { id: tank, model, size },
+            animals,
+            fixtures,
+            equipment: vec![],
+            location: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_diff_saves() {
+        let clown = test_species("clown");
+        let tang = test_species("tang");
+        let tank = test_tank_model("basic_tank");
+        let rock = test_fixture_model("rock");
+        let animal = |id, species, growth| AnimalRef { id, species, growth };
+        let young = Growth::Growing { stage: 0, growth: 2 };
+
+        let old = AquariumRef {
+            exhibits: vec![
+                exhibit(
+                    "Reef",
+                    1,
+                    &tank,
+                    (3, 3),
+                    vec![
+                        animal(10, &clown, young),
+                        animal(11, &tang, Growth::Final),
+                        animal(12, &clown, young),
+                    ],
+                    vec![FixtureRef { id: 20, model: &rock }],
+                ),
+                exhibit("Lagoon", 2, &tank, (2, 2), vec![], vec![]),
+            ],
+            numbered_animals: Default::default(),
+        };
+        let new = AquariumRef {
+            exhibits: vec![
+                exhibit(
+                    "Reef",
+                    1,
+                    &tank,
+                    (4, 3),
+                    vec![
+                        animal(10, &clown, Growth::Growing { stage: 0, growth: 3 }),
+                        animal(13, &tang, young),
+                    ],
+                    vec![],
+                ),
+                exhibit(
+                    "Kelp",
+                    3,
+                    &tank,
+                    (2, 2),
+                    vec![animal(12, &clown, Growth::Final)],
+                    vec![FixtureRef { id: 20, model: &rock }],
+                ),
+            ],
+            numbered_animals: Default::default(),
+        };
+
+        let changes: Vec<String> = diff_saves(&old, &new).iter().map(|c| c.to_string()).collect();
+        assert_eq!(
+            changes,
+            vec![
+                "~ tank 1 (Reef) resized from 3x3 to 4x3",
+                "- tank 2 (Lagoon)",
+                "+ tank 3 (Kelp) 2x2",
+                "- animal 11 (tang) disappeared from Reef",
+                "~ animal 12 (clown) moved from Reef to Kelp",
+                "~ animal 12 (clown) in Kelp grew from stage 0 to fully grown",
+                "+ animal 13 (tang) in Reef",
+                "~ fixture 20 (rock) moved from Reef to Kelp",
+            ]
+        );
+        assert!(diff_saves(&new, &new).is_empty());
+    }
+}
@@ -6,10 +6,16 @@
 use crate::tank::*;
 use crate::animal::*;
 use crate::aquarium::*;
+use crate::dlc::Dlc;
+use crate::economy::Economy;
+use crate::names::Names;
+use crate::equipment::*;
 use crate::fixture::*;
+use crate::food::FoodModel;
 use crate::sexpr_format::*;
 use crate::util;
 use lexpr::*;
+use std::collections::BTreeMap;
 
 fn add_opt_into<I: Into<Value>>(builder: &mut StructBuilder, key: &str, x:Option<I>) {
     if let Some(v) = x {
@@ -23,12 +29,32 @@ fn add_opt_sexp<S: ToSexp>(builder: &mut StructBuilder, key: &str, x:Option<S>)
     }
 }
 
+fn add_economy(builder: &mut StructBuilder, economy: &Economy) {
+    add_opt_into(builder, "cost", economy.cost);
+    add_opt_into(builder, "upkeep", economy.upkeep);
+    add_opt_into(builder, "prestige", economy.prestige);
+}
+
+fn add_names(builder: &mut StructBuilder, names: &Names) {
+    if !names.0.is_empty() {
+        let list = names.0.iter().map(|(lang, name)| Value::cons(symbol_of_string(lang), Value::string(name.clone())));
+        builder.add("names", Value::list(list))
+    }
+}
+
+fn add_dlc(builder: &mut StructBuilder, dlc: Option<Dlc>) {
+    if let Some(d) = dlc {
+        builder.add("dlc", symbol_of_str(d.as_str()))
+    }
+}
+
 impl ToSexp for Species {
     #[allow(unused_parens)]
     fn to_sexp(&self) -> lexpr::Value {
         let mut builder = StructBuilder::new("species");
 
         builder.add("id", Value::string(self.id.clone()));
+        add_names(&mut builder, &self.names);
         builder.add("genus", symbol_of_string(&self.genus));
 
         builder.add("prey-type", symbol_of_str(self.prey_type.as_str()));
@@ -107,6 +133,10 @@ impl ToSexp for Species {
         }
 
         add_opt_into(&mut builder, "communal", self.communal);
+        add_economy(&mut builder, &self.economy);
+        add_opt_into(&mut builder, "unlock-level", self.unlock_level);
+        add_dlc(&mut builder, self.dlc);
+        add_opt_into(&mut builder, "mod", self.mod_name.as_deref());
 
         builder.to_value()
     }
@@ -183,12 +213,17 @@ impl ToSexp for TankModel {
         let mut builder = StructBuilder::new("tank-model");
 
         builder.add("id", Value::string(self.id.clone()));
+        add_names(&mut builder, &self.names);
         builder.add("min-size", Value::cons(self.min_size.0, self.min_size.1));
         builder.add("max-size", Value::cons(self.max_size.0, self.max_size.1));
         builder.add("density", self.density().into());
         if let Some(t) = self.interior {
             builder.add("interior", symbol_of_str(t.as_str()));
         }
+        add_economy(&mut builder, &self.economy);
+        add_opt_into(&mut builder, "unlock-level", self.unlock_level);
+        add_dlc(&mut builder, self.dlc);
+        add_opt_into(&mut builder, "mod", self.mod_name.as_deref());
 
         builder.to_value()
     }
@@ -200,6 +235,7 @@ impl ToSexp for FixtureModel {
         let mut builder = StructBuilder::new("fixture-model");
 
         builder.add("id", Value::string(self.id.clone()));
+        add_names(&mut builder, &self.names);
         add_opt_into(&mut builder, "light", self.light);
         add_opt_into(&mut builder, "plants", self.plants);
         add_opt_into(&mut builder, "rocks", self.rocks);
@@ -208,6 +244,31 @@ impl ToSexp for FixtureModel {
         add_opt_into(&mut builder, "flat-surfaces", self.flat_surfaces);
         add_opt_into(&mut builder, "vertical-surfaces", self.vertical_surfaces);
         add_opt_into(&mut builder, "fluffy-foliage", self.fluffy_foliage);
+        add_economy(&mut builder, &self.economy);
+        add_opt_into(&mut builder, "unlock-level", self.unlock_level);
+        add_dlc(&mut builder, self.dlc);
+        add_opt_into(&mut builder, "mod", self.mod_name.as_deref());
+
+        builder.to_value()
+    }
+}
+
+impl ToSexp for FoodModel {
+    #[allow(unused_parens)]
+    fn to_sexp(&self) -> lexpr::Value {
+        let mut builder = StructBuilder::new("food-model");
+
+        builder.add("id", Value::string(self.id.clone()));
+        add_opt_into(&mut builder, "cost", self.cost);
+        if let Some(s) = &self.storage {
+            builder.add("storage", symbol_of_string(s));
+        }
+        if let Some(p) = &self.preparation {
+            builder.add("prepared-at", Value::string(p.clone()));
+        }
+        add_names(&mut builder, &self.names);
+        add_dlc(&mut builder, self.dlc);
+        add_opt_into(&mut builder, "mod", self.mod_name.as_deref());
 
         builder.to_value()
     }
@@ -228,6 +289,40 @@ impl FromSexp for Fixture {
     }
 }
 
+impl ToSexp for EquipmentModel {
+    #[allow(unused_parens)]
+    fn to_sexp(&self) -> lexpr::Value {
+        let mut builder = StructBuilder::new("equipment-model");
+
+        builder.add("id", Value::string(self.id.clone()));
+        add_opt_into(&mut builder, "filtration", self.filtration);
+        add_opt_into(&mut builder, "heating", self.heating);
+        add_opt_into(&mut builder, "cooling", self.cooling);
+        add_opt_into(&mut builder, "skimming", self.skimming);
+        add_opt_into(&mut builder, "lighting", self.lighting);
+        add_names(&mut builder, &self.names);
+        add_dlc(&mut builder, self.dlc);
+        add_opt_into(&mut builder, "mod", self.mod_name.as_deref());
+
+        builder.to_value()
+    }
+}
+
+impl ToSexp for Equipment {
+    #[allow(unused_parens)]
+    fn to_sexp(&self) -> lexpr::Value {
+        sexp!((equipment ,(self.id) ,(self.model.clone())))
+    }
+}
+
+impl FromSexp for Equipment {
+    fn from_sexp(value: &lexpr::Value) -> util::Result<Equipment> {
+        let obj = match_list_that_starts_with(value, "equipment")?;
+        let (id, model) = match_two_args(obj, match_u64, match_string)?;
+        Ok(Equipment { id, model })
+    }
+}
+
 impl ToSexp for FixtureDesc {
     #[allow(unused_parens)]
     fn to_sexp(&self) -> lexpr::Value {
@@ -287,25 +382,80 @@ impl ToSexp for AquariumDesc {
     #[allow(unused_parens)]
     fn to_sexp(&self) -> lexpr::Value {
         let exhibits = self.exhibits.iter().map(|e| e.to_sexp());
-        sexp!((aquarium ,(Value::list(exhibits))))
+        if self.elsewhere.is_empty() {
+            return sexp!((aquarium ,(Value::list(exhibits))));
+        }
+        let elsewhere = self.elsewhere.iter().map(|e| e.to_sexp());
+        sexp!((aquarium ,(Value::list(exhibits)) (elsewhere ,(Value::list(elsewhere)))))
     }
 }
 
 impl FromSexp for AquariumDesc {
     fn from_sexp(value: &lexpr::Value) -> util::Result<AquariumDesc> {
-        let obj = match_list_that_starts_with(value, "aquarium")?;
-        let iter = match_list(obj)?;
+        let mut obj = match_list_that_starts_with(value, "aquarium")?;
+        let iter = obj.next().and_then(|x| x.list_iter()).ok_or(bad_sexp("expected list of exhibits"))?;
         let exhibits: util::Result<Vec<ExhibitDesc>> = iter.map(|x| ExhibitDesc::from_sexp(x)).collect();
-        Ok(AquariumDesc { exhibits: exhibits? })
+
+        // the animals outside every exhibit are only there for whoever reads the description
+        if let Some(elsewhere) = obj.next() {
+            match_list_that_starts_with(elsewhere, "elsewhere")?;
+        }
+        if obj.next().is_some() {
+            return Err(Box::new(bad_sexp("expected only exhibits and what is elsewhere")));
+        }
+
+        Ok(AquariumDesc { exhibits: exhibits?, elsewhere: vec![] })
+    }
+}
+
+impl ToSexp for ElsewhereDesc {
+    #[allow(unused_parens)]
+    fn to_sexp(&self) -> lexpr::Value {
+        let mut builder = StructBuilder::new("stray");
+
+        builder.add("animal", AnimalDesc::Individual(self.animal.clone()).to_sexp());
+        builder.add("hosting", self.hosting.to_sexp());
+        if let Some(name) = &self.name {
+            builder.add("name", name.as_str().into());
+        }
+
+        builder.to_value()
+    }
+}
+
+impl ToSexp for Hosting {
+    #[allow(unused_parens)]
+    fn to_sexp(&self) -> lexpr::Value {
+        match self {
+            Hosting::Exhibit(tank) => sexp!((#"in-exhibit" ,(*tank))),
+            Hosting::Holding(host) => sexp!((#"held-by" ,(*host))),
+            Hosting::InTransit(staff) => sexp!((#"carried-by" ,(*staff))),
+            Hosting::Unknown => sexp!((unknown)),
+        }
     }
 }
 
 impl ToSexp for ExhibitDesc {
     #[allow(unused_parens)]
     fn to_sexp(&self) -> lexpr::Value {
-        let animals = self.animals.iter().map(|e| e.to_sexp());
-        let fixtures = self.fixtures.iter().map(|f| f.to_sexp());
-        sexp!((exhibit #:name ,(self.name.as_str()) #:tank ,(self.tank.to_sexp()) #:animals ,(Value::list(animals)) #:fixtures ,(Value::list(fixtures))))
+        let mut builder = StructBuilder::new("exhibit");
+
+        builder.add("name", self.name.as_str().into());
+        builder.add("tank", self.tank.to_sexp());
+        builder.add("animals", Value::list(self.animals.iter().map(|e| e.to_sexp())));
+        builder.add("fixtures", Value::list(self.fixtures.iter().map(|f| f.to_sexp())));
+        if !self.equipment.is_empty() {
+            builder.add("equipment", Value::list(self.equipment.iter().map(|e| e.to_sexp())));
+        }
+        if self.location != Location::default() {
+            builder.add("location", self.location.to_sexp());
+        }
+        if !self.names.is_empty() {
+            let list = self.names.iter().map(|(id, name)| Value::cons(Value::string(id.clone()), Value::string(name.clone())));
+            builder.add("names", Value::list(list));
+        }
+
+        builder.to_value()
     }
 }
 
@@ -325,7 +475,61 @@ impl FromSexp for ExhibitDesc {
             None => vec![],
         };
 
-        Ok(ExhibitDesc { name, tank, animals: animals?, fixtures })
+        let equipment = match try_consume_keyword_arg(&mut obj, "equipment")? {
+            Some(v) => {
+                let list = v.list_iter().ok_or(bad_sexp("expected equipment to be list"))?;
+                list.map(Equipment::from_sexp).collect::<util::Result<Vec<_>>>()?
+            }
+            None => vec![],
+        };
+
+        let location = match try_consume_keyword_arg(&mut obj, "location")? {
+            Some(v) => Location::from_sexp(v)?,
+            None => Location::default(),
+        };
+
+        let names = match try_consume_keyword_arg(&mut obj, "names")? {
+            Some(v) => {
+                let list = v.list_iter().ok_or(bad_sexp("expected names to be list"))?;
+                list.map(|pair| {
+                    let (id, name) = pair.as_cons().ok_or(bad_sexp("expected names to be pairs"))?.as_pair();
+                    Ok((match_string(id)?, match_string(name)?))
+                })
+                .collect::<util::Result<BTreeMap<_, _>>>()?
+            }
+            None => BTreeMap::new(),
+        };
+
+        Ok(ExhibitDesc { name, tank, animals: animals?, fixtures, equipment, location, names })
+    }
+}
+
+impl ToSexp for Location {
+    #[allow(unused_parens)]
+    fn to_sexp(&self) -> lexpr::Value {
+        let mut builder = StructBuilder::new("location");
+
+        add_opt_into(&mut builder, "floor", self.floor);
+        add_opt_into(&mut builder, "zone", self.zone.as_deref());
+        if let Some((x, y)) = self.position {
+            builder.add("position", sexp!((position ,(x) ,(y))));
+        }
+
+        builder.to_value()
+    }
+}
+
+impl FromSexp for Location {
+    fn from_sexp(value: &lexpr::Value) -> util::Result<Location> {
+        let mut obj = match_list_that_starts_with(value, "location")?;
+        let floor = try_consume_keyword_arg(&mut obj, "floor")?.map(match_u8).transpose()?;
+        let zone = try_consume_keyword_arg(&mut obj, "zone")?.map(match_string).transpose()?;
+        let position = match try_consume_keyword_arg(&mut obj, "position")? {
+            Some(v) => Some(match_two_args(match_list_that_starts_with(v, "position")?, match_i32, match_i32)?),
+            None => None,
+        };
+
+        Ok(Location { floor, zone, position })
     }
 }
 
@@ -436,15 +640,6 @@ fn match_list_that_starts_with<'a>(value: &'a lexpr::Value, opening_symbol: &str
     }
 }
 
-fn match_list<'a>(iter: lexpr::cons::ListIter<'a>) -> util::Result<lexpr::cons::ListIter<'a>> {
-    let items: Vec<&lexpr::Value> = iter.collect();
-    if items.len() != 1 {
-        return Err(Box::new(bad_sexp("expected call to have single argument")));
-    }
-    let result = items[0].list_iter().ok_or(bad_sexp("expected arg to be list"))?;
-    Ok(result)
-}
-
 trait ParseFn<T> = Fn(& Value)->util::Result<T>;
 
 fn match_two_args<'a,T,U,F1,F2>(iter: lexpr::cons::ListIter<'a>, f1:F1, f2:F2) -> util::Result<(T,U)>
@@ -482,6 +677,11 @@ fn match_u16(v: &Value) -> util::Result<u16> {
     Ok(n as u16)
 }
 
+fn match_i32(v: &Value) -> util::Result<i32> {
+    let n = v.as_number().and_then(|x| x.as_i64()).ok_or(bad_sexp("expected arg to be i32"))?;
+    Ok(n.try_into()?)
+}
+
 fn match_u64(v: &Value) -> util::Result<u64> {
     let n = v.as_number().and_then(|x| x.as_u64()).ok_or(bad_sexp("expected arg to be u64"))?;
     Ok(n)
@@ -492,9 +692,12 @@ fn match_string(v: &Value) -> util::Result<String> {
     Ok(s.to_string())
 }
 
+/// Leaves `iter` where it was if the next keyword isn't the expected one, so optional keywords
+/// can be skipped.
 fn try_consume_keyword_arg<'a>(iter: &mut lexpr::cons::ListIter<'a>, expected_keyword: &str) -> util::Result<Option<&'a lexpr::Value>> {
-    match iter.next() {
-        Some(lexpr::Value::Keyword(s)) if **s == *expected_keyword => (),
+    let mut peek = iter.clone();
+    match peek.next() {
+        Some(lexpr::Value::Keyword(s)) if **s == *expected_keyword => *iter = peek,
         _ => return Ok(None)
     };
 
@@ -594,6 +797,9 @@ mod tests {
             tank: Tank { id: 1, model: "basic_tank".to_string(), size: (4, 4) },
             animals: vec![AnimalDesc::Summary(SpeciesCount { species: "neon_tetra".to_string(), count: 10 })],
             fixtures: vec![],
+            equipment: vec![],
+            location: Default::default(),
+            names: Default::default(),
         };
         let original = exhibit.to_sexp().to_string();
         let roundtripped = roundtrip_string(&exhibit);
@@ -613,15 +819,54 @@ mod tests {
                 FixtureDesc::Individual(Fixture { id: 10, model: "live_rock".to_string() }),
                 FixtureDesc::Individual(Fixture { id: 11, model: "anemone".to_string() }),
             ],
+            equipment: vec![],
+            location: Default::default(),
+            names: Default::default(),
         };
         let original = exhibit.to_sexp().to_string();
         let roundtripped = roundtrip_string(&exhibit);
         assert_eq!(original, roundtripped);
     }
 
+    #[test]
+    fn test_exhibit_desc_roundtrip_with_location() {
+        let exhibit = ExhibitDesc {
+            name: "Kelp Forest".to_string(),
+            tank: Tank { id: 3, model: "basic_tank".to_string(), size: (3, 3) },
+            animals: vec![],
+            fixtures: vec![],
+            equipment: vec![],
+            location: Location { floor: Some(2), zone: Some("Temperate".to_string()), position: Some((-4, 12)) },
+            names: Default::default(),
+        };
+        let parsed = ExhibitDesc::from_sexp(&exhibit.to_sexp()).expect("roundtrip parse failed");
+        assert_eq!(parsed.location, exhibit.location);
+
+        let floor_only = ExhibitDesc { location: Location { floor: Some(1), ..Location::default() }, ..exhibit };
+        let parsed = ExhibitDesc::from_sexp(&floor_only.to_sexp()).expect("roundtrip parse failed");
+        assert_eq!(parsed.location, floor_only.location);
+        assert_eq!(roundtrip_string(&floor_only), floor_only.to_sexp().to_string());
+    }
+
+    #[test]
+    fn test_exhibit_desc_roundtrip_with_names() {
+        let exhibit = ExhibitDesc {
+            name: "Reef".to_string(),
+            tank: Tank { id: 1, model: "basic_tank".to_string(), size: (3, 3) },
+            animals: vec![AnimalDesc::Summary(SpeciesCount { species: "11_yellow_tang".to_string(), count: 2 })],
+            fixtures: vec![],
+            equipment: vec![],
+            location: Location::default(),
+            names: BTreeMap::from([("11_yellow_tang".to_string(), "Yellow Tang".to_string())]),
+        };
+        let parsed = ExhibitDesc::from_sexp(&exhibit.to_sexp()).expect("roundtrip parse failed");
+        assert_eq!(parsed.names, exhibit.names);
+        assert_eq!(roundtrip_string(&exhibit), exhibit.to_sexp().to_string());
+    }
+
     #[test]
     fn test_aquarium_desc_roundtrip_empty() {
-        let aquarium = AquariumDesc { exhibits: vec![] };
+        let aquarium = AquariumDesc { exhibits: vec![], elsewhere: vec![] };
         let original = aquarium.to_sexp().to_string();
         let roundtripped = roundtrip_string(&aquarium);
         assert_eq!(original, roundtripped);
@@ -635,7 +880,11 @@ mod tests {
                 tank: Tank { id: 1, model: "display_tank".to_string(), size: (10, 5) },
                 animals: vec![AnimalDesc::Summary(SpeciesCount { species: "goldfish".to_string(), count: 3 })],
                 fixtures: vec![FixtureDesc::Individual(Fixture { id: 1, model: "plant".to_string() })],
+                equipment: vec![],
+                location: Default::default(),
+                names: Default::default(),
             }],
+            elsewhere: vec![],
         };
         let original = aquarium.to_sexp().to_string();
         let roundtripped = roundtrip_string(&aquarium);
@@ -651,20 +900,58 @@ mod tests {
                     tank: Tank { id: 1, model: "tank_a".to_string(), size: (5, 5) },
                     animals: vec![AnimalDesc::Summary(SpeciesCount { species: "guppy".to_string(), count: 6 })],
                     fixtures: vec![],
+                    equipment: vec![],
+                    location: Default::default(),
+                    names: Default::default(),
                 },
                 ExhibitDesc {
                     name: "Coldwater".to_string(),
                     tank: Tank { id: 2, model: "tank_b".to_string(), size: (6, 4) },
                     animals: vec![AnimalDesc::Individual(Animal { id: 10, species: "trout".to_string(), growth: Growth::Final })],
                     fixtures: vec![FixtureDesc::Individual(Fixture { id: 20, model: "rock".to_string() })],
+                    equipment: vec![],
+                    location: Default::default(),
+                    names: Default::default(),
                 },
             ],
+            elsewhere: vec![],
         };
         let original = aquarium.to_sexp().to_string();
         let roundtripped = roundtrip_string(&aquarium);
         assert_eq!(original, roundtripped);
     }
 
+    #[test]
+    fn test_aquarium_desc_ignores_elsewhere() {
+        let aquarium = AquariumDesc {
+            exhibits: vec![],
+            elsewhere: vec![
+                ElsewhereDesc {
+                    animal: Animal { id: 2, species: "clownfish".to_string(), growth: Growth::Final },
+                    hosting: Hosting::Holding(11),
+                    name: Some("Clownfish".to_string()),
+                },
+                ElsewhereDesc {
+                    animal: Animal { id: 3, species: "goldfish".to_string(), growth: Growth::Final },
+                    hosting: Hosting::Unknown,
+                    name: None,
+                },
+            ],
+        };
+        let text = aquarium.to_sexp().to_string();
+        assert_eq!(
+            text,
+            "(aquarium () (elsewhere (\
+             (stray #:animal (animal 2 \"clownfish\" (grown)) #:hosting (held-by 11) #:name \"Clownfish\") \
+             (stray #:animal (animal 3 \"goldfish\" (grown)) #:hosting (unknown)))))"
+        );
+
+        let parsed = AquariumDesc::from_sexp(&lexpr::from_str(&text).unwrap()).unwrap();
+        assert!(parsed.exhibits.is_empty());
+        assert!(parsed.elsewhere.is_empty());
+        assert!(AquariumDesc::from_sexp(&lexpr::from_str("(aquarium () (strays ()))").unwrap()).is_err());
+    }
+
     // === ToSexp snapshot tests ===
 
     #[test]
@@ -763,6 +1050,11 @@ mod tests {
             max_size: (10, 10),
             double_density: 4,
             interior: None,
+            economy: Economy::default(),
+            names: Names::default(),
+            unlock_level: None,
+            dlc: None,
+            mod_name: None,
         };
         let result = model.to_sexp().to_string();
         assert_eq!(result, "(tank-model #:id \"basic_glass\" #:min-size (2 . 2) #:max-size (10 . 10) #:density 2.0)");
@@ -776,6 +1068,11 @@ mod tests {
             max_size: (6, 6),
             double_density: 7,
             interior: Some(Interior::Kreisel),
+            economy: Economy::default(),
+            names: Names::default(),
+            unlock_level: None,
+            dlc: None,
+            mod_name: None,
         };
         let result = model.to_sexp().to_string();
         assert_eq!(result, "(tank-model #:id \"kreisel_tank\" #:min-size (3 . 3) #:max-size (6 . 6) #:density 3.5 #:interior kreisel)");
@@ -793,6 +1090,11 @@ mod tests {
             flat_surfaces: None,
             vertical_surfaces: None,
             fluffy_foliage: None,
+            economy: Economy::default(),
+            names: Names::default(),
+            unlock_level: None,
+            dlc: None,
+            mod_name: None,
         };
         let result = model.to_sexp().to_string();
         assert_eq!(result, "(fixture-model #:id \"empty_rock\")");
@@ -810,11 +1112,31 @@ mod tests {
             flat_surfaces: Some(6),
             vertical_surfaces: Some(7),
             fluffy_foliage: Some(8),
+            economy: Economy::default(),
+            names: Names::default(),
+            unlock_level: None,
+            dlc: None,
+            mod_name: None,
         };
         let result = model.to_sexp().to_string();
         assert_eq!(result, "(fixture-model #:id \"deluxe_decoration\" #:light 3 #:plants 5 #:rocks 4 #:caves 2 #:bogwood 1 #:flat-surfaces 6 #:vertical-surfaces 7 #:fluffy-foliage 8)");
     }
 
+    #[test]
+    fn test_food_model_to_sexp() {
+        let model = FoodModel {
+            id: "krill".to_string(),
+            cost: Some(4),
+            storage: Some("frozen".to_string()),
+            preparation: Some("food_prep_table".to_string()),
+            names: Names::default(),
+            dlc: Some(Dlc::FreshwaterFrenzy),
+            mod_name: None,
+        };
+        let result = model.to_sexp().to_string();
+        assert_eq!(result, "(food-model #:id \"krill\" #:cost 4 #:storage frozen #:prepared-at \"food_prep_table\" #:dlc freshwater-frenzy)");
+    }
+
     #[test]
     fn test_species_minimal_to_sexp() {
         let species = Species {
@@ -844,6 +1166,11 @@ mod tests {
             predation: vec![],
             communal: None,
             breeding: Breeding::CannotBread,
+            economy: Economy::default(),
+            names: Names::default(),
+            unlock_level: None,
+            dlc: None,
+            mod_name: None,
         };
         let result = species.to_sexp().to_string();
         assert_eq!(result, "(species #:id \"test_fish\" #:genus Testus #:prey-type fish #:size 5 #:habitat (habitat #:temperature warm #:salinity salty #:quality 50) #:diet (no-food))");
@@ -878,6 +1205,11 @@ mod tests {
             predation: vec![],
             communal: None,
             breeding: Breeding::CannotBread,
+            economy: Economy::default(),
+            names: Names::default(),
+            unlock_level: None,
+            dlc: None,
+            mod_name: None,
         };
         let result = species.to_sexp().to_string();
         assert_eq!(result, "(species #:id \"hungry_fish\" #:genus Hungrius #:prey-type fish #:size 8 #:habitat (habitat #:temperature cold #:salinity fresh #:quality 60) #:diet (food flakes 3) #:greedy #t)");
@@ -912,6 +1244,11 @@ mod tests {
             predation: vec![],
             communal: None,
             breeding: Breeding::CannotBread,
+            economy: Economy::default(),
+            names: Names::default(),
+            unlock_level: None,
+            dlc: None,
+            mod_name: None,
         };
         let result = species.to_sexp().to_string();
         assert!(result.contains("#:diet (food krill 2 #:skill 2)"));
@@ -946,6 +1283,11 @@ mod tests {
             predation: vec![],
             communal: None,
             breeding: Breeding::CannotBread,
+            economy: Economy::default(),
+            names: Names::default(),
+            unlock_level: None,
+            dlc: None,
+            mod_name: None,
         };
         let result = species.to_sexp().to_string();
         assert_eq!(result, "(species #:id \"bottom_feeder\" #:genus Scavengus #:prey-type crustacean #:size 4 #:armored? #t #:habitat (habitat #:temperature warm #:salinity both #:quality 40) #:diet (scavenger))");
@@ -987,6 +1329,11 @@ mod tests {
             predation: vec![PreyType::Crustacean, PreyType::Baby],
             communal: Some(4),
             breeding: Breeding::CannotBread,
+            economy: Economy::default(),
+            names: Names::default(),
+            unlock_level: None,
+            dlc: None,
+            mod_name: None,
         };
         let result = species.to_sexp().to_string();
         assert!(result.contains("#:id \"complex_fish\""));
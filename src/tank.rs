@@ -2,8 +2,12 @@
 
 use crate::{
     data::GameData,
+    dlc::Dlc,
+    economy::Economy,
+    names::Names,
     util::{as_str_display, Result},
 };
+use serde::{Deserialize, Serialize};
 
 pub type TankId = u64;
 
@@ -39,7 +43,7 @@ impl TankRef<'_> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TankModel {
     pub id: String,
     pub min_size: (u16, u16),
@@ -47,6 +51,11 @@ pub struct TankModel {
     // some tanks have, e.g., 3.5 vol/tile, so we store double density to avoid floats
     pub double_density: u16,
     pub interior: Option<Interior>,
+    pub names: Names,
+    pub economy: Economy,
+    pub unlock_level: Option<u8>,
+    pub dlc: Option<Dlc>,
+    pub mod_name: Option<String>,
 }
 
 impl TankModel {
@@ -77,7 +86,7 @@ pub struct Environment {
 }
 
 /// The properties of a tank that we can load from a save file.
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
 pub struct LoadedEnvironment {
     pub size: u16,
     pub light: u8,
@@ -92,7 +101,7 @@ pub struct LoadedEnvironment {
     pub different_decorations: u8,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Interior {
     Rounded,
     Kreisel,
@@ -109,7 +118,7 @@ impl Interior {
 
 as_str_display!(Interior);
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Temperature {
     Warm,
     Cold,
@@ -139,7 +148,7 @@ impl Default for Temperature {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Salinity {
     Salty,
     Fresh,
@@ -184,6 +193,11 @@ pub mod test {
             max_size: (10, 10),
             double_density: 2, // 1.0 volume per tile
             interior: None,
+            economy: Economy::default(),
+            names: Names::default(),
+            unlock_level: None,
+            dlc: None,
+            mod_name: None,
         }
     }
 }
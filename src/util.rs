@@ -33,3 +33,37 @@ macro_rules! as_str_display {
     };
 }
 pub(crate) use as_str_display;
+
+#[cfg(test)]
+pub mod test {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    /// A scratch directory that is removed when dropped, so a failing assert doesn't leave it behind.
+    pub struct TempDir(PathBuf);
+
+    impl TempDir {
+        pub fn new(name: &str) -> TempDir {
+            let dir = std::env::temp_dir().join(format!("megaquarium-{}-test-{}", name, std::process::id()));
+            fs::create_dir_all(&dir).unwrap();
+            TempDir(dir)
+        }
+
+        pub fn path(&self) -> &Path {
+            &self.0
+        }
+
+        /// Writes `json` to a save file in the directory and returns its path.
+        pub fn save(&self, json: &str) -> PathBuf {
+            let path = self.0.join("park.sav");
+            fs::write(&path, json).unwrap();
+            path
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+// pattern: Functional Core
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::aquarium::AquariumRef;
+use crate::check::AquariumCheckResult;
+
+/// The problems in each exhibit, as they are printed, by the uid of its tank so renaming an
+/// exhibit doesn't look like one going away and another appearing.
+pub type Problems = BTreeMap<u64, ExhibitProblems>;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExhibitProblems {
+    pub name: String,
+    pub problems: BTreeSet<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ProblemChanges {
+    pub exhibit: String,
+    pub introduced: Vec<String>,
+    pub resolved: Vec<String>,
+}
+
+/// `result` must be the validation of `aquarium`.
+pub fn problems(aquarium: &AquariumRef, result: &AquariumCheckResult) -> Problems {
+    aquarium
+        .exhibits
+        .iter()
+        .zip(&result.exhibits)
+        .map(|(exhibit, validation)| {
+            let problems = ExhibitProblems {
+                name: validation.name.clone(),
+                problems: validation.violations.iter().map(|v| v.to_string()).collect(),
+            };
+            (exhibit.tank.id, problems)
+        })
+        .collect()
+}
+
+/// What changed between two validations of the same save, by exhibit. Exhibits that appear or
+/// disappear bring or take all of their problems with them. Exhibits are named as they are now,
+/// or as they were last if they are gone.
+pub fn problem_changes(old: &Problems, new: &Problems) -> Vec<ProblemChanges> {
+    let empty = ExhibitProblems::default();
+    let tanks: BTreeSet<&u64> = old.keys().chain(new.keys()).collect();
+
+    tanks
+        .into_iter()
+        .filter_map(|tank| {
+            let before = old.get(tank).unwrap_or(&empty);
+            let after = new.get(tank).unwrap_or(&empty);
+            let exhibit = if new.contains_key(tank) { &after.name } else { &before.name };
+
+            let changes = ProblemChanges {
+                exhibit: exhibit.clone(),
+                introduced: after.problems.difference(&before.problems).cloned().collect(),
+                resolved: before.problems.difference(&after.problems).cloned().collect(),
+            };
+
+            if changes.introduced.is_empty() && changes.resolved.is_empty() {
+                None
+            } else {
+                Some(changes)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn problems_of(list: &[(u64, &str, &[&str])]) -> Problems {
+        list.iter()
+            .map(|(tank, name, ps)| {
+                let problems = ExhibitProblems {
+                    name: name.to_string(),
+                    problems: ps.iter().map(|p| p.to_string()).collect(),
+                };
+                (*tank, problems)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_problem_changes() {
+        let old = problems_of(&[
+            (1, "Reef", &["tang requires at least quality 70", "clown requires warm tank"]),
+            (2, "Lagoon", &["eel will eat goby"]),
+            (3, "Pond", &[]),
+            (5, "Tank 5", &["goby requires at least 1 cave"]),
+        ]);
+        let new = problems_of(&[
+            (1, "Reef", &["tang requires at least quality 70", "tang requires at least 2 light"]),
+            (3, "Pond", &[]),
+            (4, "Kelp", &["otter requires cold tank"]),
+            (5, "Goby Cave", &[]),
+        ]);
+
+        assert_eq!(
+            problem_changes(&old, &new),
+            vec![
+                ProblemChanges {
+                    exhibit: "Reef".to_string(),
+                    introduced: vec!["tang requires at least 2 light".to_string()],
+                    resolved: vec!["clown requires warm tank".to_string()],
+                },
+                ProblemChanges {
+                    exhibit: "Lagoon".to_string(),
+                    introduced: vec![],
+                    resolved: vec!["eel will eat goby".to_string()],
+                },
+                ProblemChanges {
+                    exhibit: "Kelp".to_string(),
+                    introduced: vec!["otter requires cold tank".to_string()],
+                    resolved: vec![],
+                },
+                // renamed, but the same tank
+                ProblemChanges {
+                    exhibit: "Goby Cave".to_string(),
+                    introduced: vec![],
+                    resolved: vec!["goby requires at least 1 cave".to_string()],
+                },
+            ]
+        );
+        assert_eq!(problem_changes(&new, &new), vec![]);
+    }
+}
@@ -0,0 +1,123 @@
+// pattern: Functional Core
+
+// The game degrades water quality when a tank produces more waste than its life support can deal
+// with. We approximate that as: every animal adds its current size plus what it eats per day to the
+// bioload, filters and protein skimmers remove up to their capacity, and the water can be kept at
+// the fraction of the bioload that gets removed.
+
+use crate::animal::AnimalRef;
+use crate::equipment::LifeSupport;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WaterQuality {
+    pub bioload: u16,
+    /// filtration plus protein skimming
+    pub capacity: u16,
+    /// the best quality the tank can be kept at, in percent
+    pub achievable: u8,
+}
+
+impl WaterQuality {
+    /// None if the exhibit has no water treatment at all, which for plans usually means the
+    /// equipment just wasn't listed, so we can't say anything about quality.
+    pub fn of(animals: &[AnimalRef], life_support: &LifeSupport) -> Option<WaterQuality> {
+        let capacity = life_support.filtration.saturating_add(life_support.skimming);
+        if capacity == 0 {
+            return None;
+        }
+
+        let bioload = bioload(animals);
+
+        Some(WaterQuality {
+            bioload,
+            capacity,
+            achievable: achievable_quality(bioload, capacity),
+        })
+    }
+}
+
+/// Saturates rather than overflowing, which only makes the achievable quality round down to 0.
+pub fn bioload(animals: &[AnimalRef]) -> u16 {
+    animals.iter().fold(0u16, |total, a| {
+        total.saturating_add(a.size()).saturating_add(a.species.amount_food_eaten())
+    })
+}
+
+fn achievable_quality(bioload: u16, capacity: u16) -> u8 {
+    if bioload <= capacity {
+        100
+    } else {
+        (100 * capacity as u32 / bioload as u32) as u8
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::animal::test::test_species;
+    use crate::animal::*;
+
+    fn filtration(amount: u16) -> LifeSupport {
+        LifeSupport {
+            filtration: amount,
+            ..LifeSupport::default()
+        }
+    }
+
+    #[test]
+    fn test_bioload_counts_size_and_food() {
+        let species = Species {
+            diet: Diet::Food {
+                food: "flakes".to_string(),
+                period: 1,
+                skill: 0,
+            },
+            ..test_species("tang")
+        };
+        let animal = AnimalRef {
+            id: 1,
+            species: &species,
+            growth: Growth::Final,
+        };
+
+        // size 5, eats 5 a day
+        assert_eq!(bioload(&[animal, animal]), 20);
+
+        let whale = Species {
+            size: Size {
+                final_size: u16::MAX - 1,
+                ..species.size.clone()
+            },
+            ..species.clone()
+        };
+        let animal = AnimalRef {
+            id: 2,
+            species: &whale,
+            growth: Growth::Final,
+        };
+        assert_eq!(bioload(&[animal, animal]), u16::MAX);
+    }
+
+    #[test]
+    fn test_achievable_quality() {
+        let species = test_species("tang");
+        let animals: Vec<_> = (0..4)
+            .map(|id| AnimalRef {
+                id,
+                species: &species,
+                growth: Growth::Final,
+            })
+            .collect();
+
+        assert_eq!(WaterQuality::of(&animals, &LifeSupport::default()), None);
+
+        let enough = WaterQuality::of(&animals, &filtration(20)).unwrap();
+        assert_eq!((enough.bioload, enough.capacity, enough.achievable), (20, 20, 100));
+
+        let skimmed = LifeSupport {
+            skimming: 5,
+            ..filtration(10)
+        };
+        assert_eq!(WaterQuality::of(&animals, &skimmed).unwrap().achievable, 75);
+    }
+}